@@ -0,0 +1,118 @@
+//! Benchmarks of decoding big payloads, run with `cargo bench` and again with
+//! `cargo bench --features simd-json` to compare serde_json against simd-json
+//!
+//! The payloads are built from the fixtures in `benches/fixtures` by repeating their items up to
+//! the size of a big page.
+use covalent_class_a::fast::{self, LogEventsDataRef, TransactionsDataRef};
+use covalent_class_a::resources::{LogEventsGenericData, TransactionsData};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use serde_json::{json, Value};
+use std::hint::black_box;
+
+const TRANSACTIONS: &str = include_str!("fixtures/transactions.json");
+// the number of items in a payload, the largest page size the API allows
+const PAGE_SIZE: usize = 1000;
+
+/// Repeat a list of items until it has `PAGE_SIZE` items
+fn repeat(items: &[Value]) -> Vec<Value> {
+    items.iter().cycle().take(PAGE_SIZE).cloned().collect()
+}
+
+/// A page of transactions with their log events
+fn transactions_payload() -> Vec<u8> {
+    let mut transactions: Value = serde_json::from_str(TRANSACTIONS).unwrap();
+    let items = repeat(transactions["data"]["items"].as_array().unwrap());
+    transactions["data"]["items"] = Value::Array(items);
+    serde_json::to_vec(&transactions).unwrap()
+}
+
+/// A page of log events, taken from the log events of the transactions
+fn log_events_payload() -> Vec<u8> {
+    let transactions: Value = serde_json::from_str(TRANSACTIONS).unwrap();
+    let log_events: Vec<Value> = transactions["data"]["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|item| item["log_events"].as_array().unwrap().clone())
+        .collect();
+    serde_json::to_vec(&json!({
+        "data": {
+            "updated_at": "2022-05-20T10:38:30Z",
+            "items": repeat(&log_events),
+            "has_more": true,
+            "page_number": null,
+            "page_size": PAGE_SIZE,
+            "total_count": null
+        },
+        "error": false,
+        "error_message": null,
+        "error_code": null
+    }))
+    .unwrap()
+}
+
+fn decode_transactions(c: &mut Criterion) {
+    let payload = transactions_payload();
+    let mut group = c.benchmark_group("transactions");
+    group.throughput(Throughput::Bytes(payload.len() as u64));
+    // each iteration decodes a fresh copy as simd-json parses in place
+    group.bench_function("serde_json owned", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |json| black_box(serde_json::from_slice::<TransactionsData>(json).unwrap()),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("fast owned", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |json| black_box(fast::from_slice::<TransactionsData>(json).unwrap()),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("fast borrowed", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |json| {
+                let transactions: TransactionsDataRef = fast::from_slice(json).unwrap();
+                black_box(transactions.data.items.len())
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn decode_log_events(c: &mut Criterion) {
+    let payload = log_events_payload();
+    let mut group = c.benchmark_group("log_events");
+    group.throughput(Throughput::Bytes(payload.len() as u64));
+    group.bench_function("serde_json owned", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |json| black_box(serde_json::from_slice::<LogEventsGenericData>(json).unwrap()),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("fast owned", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |json| black_box(fast::from_slice::<LogEventsGenericData>(json).unwrap()),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("fast borrowed", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |json| {
+                let log_events: LogEventsDataRef = fast::from_slice(json).unwrap();
+                black_box(log_events.data.map(|data| data.items.len()))
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, decode_transactions, decode_log_events);
+criterion_main!(benches);
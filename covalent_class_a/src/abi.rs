@@ -0,0 +1,622 @@
+//! Local decoding of raw log events from contract ABIs
+//!
+//! Covalent leaves `decoded` as `None` for logs of contracts it has no ABI for. An `AbiRegistry`
+//! holds user-supplied ABI JSON, registered either for a single contract address or globally by
+//! event signature, and decodes `raw_log_topics`/`raw_log_data` into the same `LogDecoded`
+//! representation that Covalent returns.
+//! ```
+//! use covalent_class_a::abi::AbiRegistry;
+//!
+//! let mut registry = AbiRegistry::new();
+//! registry
+//!     .register_global_abi(r#"[{"type":"event","name":"Transfer","anonymous":false,"inputs":[
+//!         {"name":"from","type":"address","indexed":true},
+//!         {"name":"to","type":"address","indexed":true},
+//!         {"name":"value","type":"uint256","indexed":false}]}]"#)
+//!     .unwrap();
+//! ```
+use crate::amount::{I256, U256};
+use crate::param::{array_element_type, ParamValue};
+use crate::resources::{LogDecoded, LogDecodedParams, LogEventItem};
+use serde::Deserialize;
+use serde_json::Map;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Keccak-256 hash of some bytes
+pub fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(bytes);
+    hasher.finalize(&mut output);
+    output
+}
+
+/// The topic0 hash of an event signature such as `Transfer(address,address,uint256)`
+pub fn event_topic(signature: &str) -> String {
+    let signature: String = signature.chars().filter(|c| !c.is_whitespace()).collect();
+    format!("0x{}", hex::encode(keccak256(signature.as_bytes())))
+}
+
+/// A parameter as it appears in ABI JSON
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct AbiParam {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub param_type: String,
+    #[serde(default)]
+    pub indexed: bool,
+    #[serde(default)]
+    pub components: Vec<AbiParam>,
+}
+
+impl AbiParam {
+    /// The canonical type used in signatures, with tuples expanded into their components
+    pub fn canonical_type(&self) -> String {
+        match self.param_type.strip_prefix("tuple") {
+            Some(array_suffix) => format!(
+                "({}){}",
+                self.components
+                    .iter()
+                    .map(AbiParam::canonical_type)
+                    .collect::<Vec<_>>()
+                    .join(","),
+                array_suffix
+            ),
+            None => self.param_type.clone(),
+        }
+    }
+}
+
+/// An event as it appears in ABI JSON
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct AbiEvent {
+    pub name: String,
+    #[serde(default)]
+    pub inputs: Vec<AbiParam>,
+    #[serde(default)]
+    pub anonymous: bool,
+}
+
+impl AbiEvent {
+    /// The canonical signature e.g. `Transfer(address,address,uint256)`
+    pub fn signature(&self) -> String {
+        format!(
+            "{}({})",
+            self.name,
+            self.inputs
+                .iter()
+                .map(AbiParam::canonical_type)
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    }
+
+    /// The topic0 hash of the event
+    pub fn topic(&self) -> String {
+        event_topic(&self.signature())
+    }
+
+    /// Decode raw topics and data into the same representation Covalent returns
+    pub fn decode(&self, topics: &[String], data: &str) -> Result<LogDecoded, Box<dyn Error>> {
+        let indexed_topics = if self.anonymous {
+            topics
+        } else {
+            topics.get(1..).unwrap_or_default()
+        };
+        let indexed_count = self.inputs.iter().filter(|input| input.indexed).count();
+        if indexed_topics.len() != indexed_count {
+            return Err(format!(
+                "Event {} expects {} indexed topics but the log has {}",
+                self.name,
+                indexed_count,
+                indexed_topics.len()
+            )
+            .into());
+        }
+
+        let data = hex::decode(data.trim_start_matches("0x"))?;
+        let data_types: Vec<AbiType> = self
+            .inputs
+            .iter()
+            .filter(|input| !input.indexed)
+            .map(AbiType::from_param)
+            .collect::<Result<_, _>>()?;
+        let mut data_values = decode_params(&data_types, &data)?.into_iter();
+        let mut indexed_topics = indexed_topics.iter();
+
+        let mut params = vec![];
+        for input in &self.inputs {
+            let value = if input.indexed {
+                let topic = hex::decode(indexed_topics.next().unwrap().trim_start_matches("0x"))?;
+                let abi_type = AbiType::from_param(input)?;
+                if abi_type.is_hashed_in_topic() {
+                    ParamValue::Bytes(topic)
+                } else {
+                    decode_param(&abi_type, &topic, 0)?
+                }
+            } else {
+                data_values.next().unwrap()
+            };
+            params.push(LogDecodedParams {
+                name: input.name.clone(),
+                param_type: input.param_type.clone(),
+                indexed: input.indexed,
+                decoded: true,
+                value: Some(value),
+                extra: Map::new(),
+            });
+        }
+
+        Ok(LogDecoded {
+            name: self.name.clone(),
+            signature: self.covalent_signature(),
+            params: Some(params),
+            extra: Map::new(),
+        })
+    }
+
+    /// The signature in the style Covalent returns, e.g.
+    /// `Transfer(indexed address from, indexed address to, uint256 value)`
+    fn covalent_signature(&self) -> String {
+        let inputs: Vec<String> = self
+            .inputs
+            .iter()
+            .map(|input| {
+                let indexed = if input.indexed { "indexed " } else { "" };
+                format!("{}{} {}", indexed, input.param_type, input.name)
+            })
+            .collect();
+        format!("{}({})", self.name, inputs.join(", "))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AbiJson {
+    Items(Vec<serde_json::Value>),
+    Artifact { abi: Vec<serde_json::Value> },
+}
+
+/// Parse the events out of ABI JSON, either a bare ABI array or a build artifact with an `abi` key
+pub fn parse_abi_events(abi_json: &str) -> Result<Vec<AbiEvent>, Box<dyn Error>> {
+    let items = match serde_json::from_str(abi_json)? {
+        AbiJson::Items(items) => items,
+        AbiJson::Artifact { abi } => abi,
+    };
+    let mut events = vec![];
+    for item in items {
+        if item.get("type").and_then(|t| t.as_str()) == Some("event") {
+            events.push(serde_json::from_value(item)?);
+        }
+    }
+    Ok(events)
+}
+
+/// Event ABIs to decode raw logs with, registered per contract address or globally
+#[derive(Debug, Clone, Default)]
+pub struct AbiRegistry {
+    contracts: HashMap<String, HashMap<String, AbiEvent>>,
+    // anonymous events have no topic0 to look them up by, so are kept apart per contract
+    anonymous: HashMap<String, Vec<AbiEvent>>,
+    global: HashMap<String, Vec<AbiEvent>>,
+}
+
+impl AbiRegistry {
+    pub fn new() -> AbiRegistry {
+        AbiRegistry::default()
+    }
+
+    /// Register the events in an ABI for logs emitted by one contract address
+    pub fn register_contract_abi(
+        &mut self,
+        contract_addr: &str,
+        abi_json: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let contract_addr = contract_addr.to_lowercase();
+        for event in parse_abi_events(abi_json)? {
+            if event.anonymous {
+                let events = self.anonymous.entry(contract_addr.clone()).or_default();
+                if !events.contains(&event) {
+                    events.push(event);
+                }
+            } else {
+                let events = self.contracts.entry(contract_addr.clone()).or_default();
+                events.insert(event.topic(), event);
+            }
+        }
+        Ok(())
+    }
+
+    /// Register the events in an ABI JSON file for logs emitted by one contract address
+    pub fn register_contract_abi_file(
+        &mut self,
+        contract_addr: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.register_contract_abi(contract_addr, &fs::read_to_string(path)?)
+    }
+
+    /// Register the events in an ABI for logs emitted by any contract, matched by event signature.
+    /// Anonymous events have no signature to match so are skipped, register them for a contract
+    pub fn register_global_abi(&mut self, abi_json: &str) -> Result<(), Box<dyn Error>> {
+        for event in parse_abi_events(abi_json)?
+            .into_iter()
+            .filter(|event| !event.anonymous)
+        {
+            let events = self.global.entry(event.topic()).or_default();
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+        Ok(())
+    }
+
+    /// Register the events in an ABI JSON file for logs emitted by any contract
+    pub fn register_global_abi_file(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.register_global_abi(&fs::read_to_string(path)?)
+    }
+
+    /// Decode raw log topics and data emitted by a contract
+    ///
+    /// An ABI registered for the contract is tried first, then any global ABIs with a matching
+    /// topic0, then the anonymous events registered for the contract. Events sharing a signature
+    /// but differing in which params are indexed, like ERC20 and ERC721 `Transfer`, are told apart
+    /// by the number of topics on the log, as are anonymous events.
+    pub fn decode_raw(
+        &self,
+        contract_addr: &str,
+        topics: &[String],
+        data: &str,
+    ) -> Option<LogDecoded> {
+        let contract_addr = contract_addr.to_lowercase();
+        let topic0 = topics.first().map(|topic| topic.to_lowercase());
+        let contract_event = topic0.as_ref().and_then(|topic0| {
+            self.contracts
+                .get(&contract_addr)
+                .and_then(|events| events.get(topic0))
+        });
+        let global_events = topic0
+            .as_ref()
+            .and_then(|topic0| self.global.get(topic0))
+            .into_iter()
+            .flatten();
+        let anonymous_events = self.anonymous.get(&contract_addr).into_iter().flatten();
+        contract_event
+            .into_iter()
+            .chain(global_events)
+            .chain(anonymous_events)
+            .find_map(|event| event.decode(topics, data).ok())
+    }
+
+    /// Decode a log event locally from its raw topics and data
+    pub fn decode(&self, log: &LogEventItem) -> Option<LogDecoded> {
+        let topics = log.raw_log_topics.as_deref()?;
+        self.decode_raw(
+            &log.sender_address,
+            topics,
+            log.raw_log_data.as_deref().unwrap_or_default(),
+        )
+    }
+
+    /// Fill in `decoded` on logs Covalent could not decode, returning how many were decoded locally
+    pub fn fill_decoded(&self, logs: &mut [LogEventItem]) -> usize {
+        let mut filled = 0;
+        for log in logs.iter_mut().filter(|log| log.decoded.is_none()) {
+            log.decoded = self.decode(log);
+            if log.decoded.is_some() {
+                filled += 1;
+            }
+        }
+        filled
+    }
+}
+
+/// A solidity type parsed from an ABI parameter
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum AbiType {
+    Address,
+    Uint,
+    Int,
+    Bool,
+    FixedBytes(usize),
+    Bytes,
+    String,
+    Array(Box<AbiType>),
+    FixedArray(Box<AbiType>, usize),
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    fn from_param(param: &AbiParam) -> Result<AbiType, Box<dyn Error>> {
+        AbiType::parse(&param.param_type, &param.components)
+    }
+
+    fn parse(param_type: &str, components: &[AbiParam]) -> Result<AbiType, Box<dyn Error>> {
+        if let Some(element_type) = array_element_type(param_type) {
+            let element = Box::new(AbiType::parse(element_type, components)?);
+            let size = &param_type[element_type.len() + 1..param_type.len() - 1];
+            return Ok(match size {
+                "" => AbiType::Array(element),
+                size => AbiType::FixedArray(element, size.parse()?),
+            });
+        }
+        Ok(match param_type {
+            "address" => AbiType::Address,
+            "bool" => AbiType::Bool,
+            "bytes" => AbiType::Bytes,
+            "string" => AbiType::String,
+            "tuple" => AbiType::Tuple(
+                components
+                    .iter()
+                    .map(AbiType::from_param)
+                    .collect::<Result<_, _>>()?,
+            ),
+            t if t.starts_with("uint") => AbiType::Uint,
+            t if t.starts_with("int") => AbiType::Int,
+            t if t.starts_with("bytes") => match t[5..].parse()? {
+                size @ 1..=32 => AbiType::FixedBytes(size),
+                _ => return Err(format!("Invalid ABI type {}", t).into()),
+            },
+            t => return Err(format!("Unsupported ABI type {}", t).into()),
+        })
+    }
+
+    /// Whether an indexed value of the type is only stored in its topic as the keccak hash of its
+    /// encoding, as are dynamic values, arrays and tuples
+    fn is_hashed_in_topic(&self) -> bool {
+        self.is_dynamic() || matches!(self, AbiType::FixedArray(..) | AbiType::Tuple(_))
+    }
+
+    fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::Bytes | AbiType::String | AbiType::Array(_) => true,
+            AbiType::FixedArray(element, _) => element.is_dynamic(),
+            AbiType::Tuple(components) => components.iter().any(AbiType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// The number of bytes the type takes up in the head of an encoding
+    fn head_size(&self) -> usize {
+        match self {
+            AbiType::FixedArray(element, size) if !self.is_dynamic() => element.head_size() * size,
+            AbiType::Tuple(components) if !self.is_dynamic() => {
+                components.iter().map(AbiType::head_size).sum()
+            }
+            _ => 32,
+        }
+    }
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<&[u8], Box<dyn Error>> {
+    data.get(offset..)
+        .and_then(|data| data.get(..32))
+        .ok_or_else(|| format!("ABI data too short to read a word at offset {}", offset).into())
+}
+
+fn read_usize(data: &[u8], offset: usize) -> Result<usize, Box<dyn Error>> {
+    let word = read_word(data, offset)?;
+    let value = U256::from_be_bytes(word.try_into()?);
+    usize::try_from(value)
+        .map_err(|_| format!("ABI offset or length {} is too large", value).into())
+}
+
+/// Decode a sequence of values encoded one after another, as in log data or a tuple
+fn decode_params(types: &[AbiType], data: &[u8]) -> Result<Vec<ParamValue>, Box<dyn Error>> {
+    let mut values = vec![];
+    let mut head = 0;
+    for abi_type in types {
+        let value = if abi_type.is_dynamic() {
+            let offset = read_usize(data, head)?;
+            decode_param(abi_type, data, offset)?
+        } else {
+            decode_param(abi_type, data, head)?
+        };
+        values.push(value);
+        head += abi_type.head_size();
+    }
+    Ok(values)
+}
+
+fn decode_param(
+    abi_type: &AbiType,
+    data: &[u8],
+    offset: usize,
+) -> Result<ParamValue, Box<dyn Error>> {
+    Ok(match abi_type {
+        AbiType::Address => ParamValue::Address(format!(
+            "0x{}",
+            hex::encode(&read_word(data, offset)?[12..])
+        )),
+        AbiType::Uint => {
+            ParamValue::Uint(U256::from_be_bytes(read_word(data, offset)?.try_into()?))
+        }
+        AbiType::Int => ParamValue::Int(I256::from_be_bytes(read_word(data, offset)?.try_into()?)),
+        AbiType::Bool => ParamValue::Bool(read_word(data, offset)?.iter().any(|b| *b != 0)),
+        AbiType::FixedBytes(size) => ParamValue::Bytes(read_word(data, offset)?[..*size].to_vec()),
+        AbiType::Bytes | AbiType::String => {
+            let len = read_usize(data, offset)?;
+            let bytes = data
+                .get(offset + 32..)
+                .and_then(|data| data.get(..len))
+                .ok_or("ABI data too short for bytes value")?
+                .to_vec();
+            match abi_type {
+                AbiType::String => ParamValue::String(String::from_utf8(bytes)?),
+                _ => ParamValue::Bytes(bytes),
+            }
+        }
+        AbiType::Array(element) => {
+            let len = read_usize(data, offset)?;
+            if len > (data.len() - offset) / 32 {
+                return Err(format!("ABI array length {} exceeds the data", len).into());
+            }
+            let elements = vec![*element.clone(); len];
+            ParamValue::Array(decode_params(
+                &elements,
+                data.get(offset + 32..).unwrap_or_default(),
+            )?)
+        }
+        AbiType::FixedArray(element, size) => {
+            let elements = vec![*element.clone(); *size];
+            ParamValue::Array(decode_params(
+                &elements,
+                data.get(offset..).unwrap_or_default(),
+            )?)
+        }
+        AbiType::Tuple(components) => ParamValue::Tuple(decode_params(
+            components,
+            data.get(offset..).unwrap_or_default(),
+        )?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERC20_ABI: &str = r#"[{"type":"event","name":"Transfer","anonymous":false,"inputs":[
+        {"name":"from","type":"address","indexed":true},
+        {"name":"to","type":"address","indexed":true},
+        {"name":"value","type":"uint256","indexed":false}]}]"#;
+
+    fn word(hex_value: &str) -> String {
+        format!("{:0>64}", hex_value)
+    }
+
+    #[test]
+    fn test_event_topic() {
+        assert_eq!(
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            event_topic("Transfer(address, address, uint256)")
+        );
+    }
+
+    #[test]
+    fn test_decode_erc20_transfer() {
+        let mut registry = AbiRegistry::new();
+        registry.register_global_abi(ERC20_ABI).unwrap();
+        let topics = vec![
+            event_topic("Transfer(address,address,uint256)"),
+            format!("0x{}", word("f4024faad5fafd0755875e3161524c9c4e1a1111")),
+            format!("0x{}", word("5c74070fdea071359b86082bd9f9b3deaafbe32b")),
+        ];
+        let decoded = registry
+            .decode_raw("0xanycontract", &topics, &format!("0x{}", word("3e8")))
+            .expect("Should decode transfer");
+        assert_eq!("Transfer", decoded.name);
+        assert_eq!(
+            Some("0xf4024faad5fafd0755875e3161524c9c4e1a1111"),
+            decoded.param("from").and_then(ParamValue::as_address)
+        );
+        assert_eq!(
+            Some(U256::new(1000)),
+            decoded.param("value").and_then(ParamValue::as_uint)
+        );
+        // an ERC721 Transfer shares topic0 but has all 3 params indexed
+        assert_eq!(None, registry.decode_raw("0xanycontract", &topics[..2], ""));
+    }
+
+    #[test]
+    fn test_decode_hashed_topics() {
+        let abi = r#"[{"type":"event","name":"Pair","inputs":[
+            {"name":"ids","type":"uint256[2]","indexed":true},
+            {"name":"point","type":"tuple","indexed":true,"components":[
+                {"name":"x","type":"uint256"},{"name":"y","type":"uint256"}]},
+            {"name":"value","type":"uint256","indexed":false}]}]"#;
+        let mut registry = AbiRegistry::new();
+        registry.register_global_abi(abi).unwrap();
+        // fixed-size arrays and tuples are indexed as the keccak hash of their encoding
+        let ids_hash = keccak256(&hex::decode([word("1"), word("2")].concat()).unwrap());
+        let point_hash = keccak256(&hex::decode([word("3"), word("4")].concat()).unwrap());
+        let topics = vec![
+            event_topic("Pair(uint256[2],(uint256,uint256),uint256)"),
+            format!("0x{}", hex::encode(ids_hash)),
+            format!("0x{}", hex::encode(point_hash)),
+        ];
+        let decoded = registry
+            .decode_raw("0xanycontract", &topics, &format!("0x{}", word("5")))
+            .expect("Should decode pair");
+        assert_eq!(
+            Some(&ParamValue::Bytes(ids_hash.to_vec())),
+            decoded.param("ids")
+        );
+        assert_eq!(
+            Some(&ParamValue::Bytes(point_hash.to_vec())),
+            decoded.param("point")
+        );
+        assert_eq!(
+            Some(U256::new(5)),
+            decoded.param("value").and_then(ParamValue::as_uint)
+        );
+    }
+
+    #[test]
+    fn test_decode_anonymous() {
+        let abi = r#"[{"type":"event","name":"Ping","anonymous":true,"inputs":[
+            {"name":"from","type":"address","indexed":true},
+            {"name":"value","type":"uint256","indexed":false}]}]"#;
+        let mut registry = AbiRegistry::new();
+        registry.register_contract_abi("0xABC", abi).unwrap();
+        registry.register_global_abi(abi).unwrap();
+        // the only topic is the indexed param, there is no signature topic
+        let topics = vec![format!(
+            "0x{}",
+            word("f4024faad5fafd0755875e3161524c9c4e1a1111")
+        )];
+        let data = format!("0x{}", word("5"));
+        let decoded = registry
+            .decode_raw("0xabc", &topics, &data)
+            .expect("Should decode anonymous ping");
+        assert_eq!("Ping", decoded.name);
+        assert_eq!(
+            Some("0xf4024faad5fafd0755875e3161524c9c4e1a1111"),
+            decoded.param("from").and_then(ParamValue::as_address)
+        );
+        // anonymous events are only decoded for the contract they are registered for
+        assert_eq!(None, registry.decode_raw("0xdef", &topics, &data));
+        // nor for logs with a different number of topics
+        assert_eq!(None, registry.decode_raw("0xabc", &[], &data));
+    }
+
+    #[test]
+    fn test_decode_dynamic_data() {
+        let abi = r#"{"abi":[{"type":"event","name":"Note","inputs":[
+            {"name":"ids","type":"uint256[]","indexed":false},
+            {"name":"memo","type":"string","indexed":false}]}]}"#;
+        let mut registry = AbiRegistry::new();
+        registry.register_contract_abi("0xABC", abi).unwrap();
+        let data = [
+            word("40"),
+            word("a0"),
+            word("2"),
+            word("1"),
+            word("2"),
+            word("2"),
+            format!("{:0<64}", hex::encode("hi")),
+        ]
+        .concat();
+        let decoded = registry
+            .decode_raw("0xabc", &[event_topic("Note(uint256[],string)")], &data)
+            .expect("Should decode note");
+        assert_eq!(
+            Some("hi"),
+            decoded.param("memo").and_then(ParamValue::as_str)
+        );
+        assert_eq!(
+            Some(2),
+            decoded
+                .param("ids")
+                .and_then(ParamValue::as_array)
+                .map(<[_]>::len)
+        );
+    }
+}
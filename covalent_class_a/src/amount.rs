@@ -0,0 +1,199 @@
+//! Lossless token amounts
+//!
+//! Covalent returns raw token amounts as decimal strings that regularly exceed 64 bits, e.g. the
+//! total supply of an 18-decimal token. These are deserialized into 256-bit integers and can be
+//! scaled by `contract_decimals` into exact `Decimal` values.
+use bigdecimal::num_bigint::{BigInt, Sign};
+use std::str::FromStr;
+
+pub use bigdecimal::BigDecimal as Decimal;
+pub use ethnum::{I256, U256};
+
+/// Scale a raw unsigned amount by the contract decimals into an exact decimal value
+pub fn to_decimal(raw: U256, contract_decimals: i32) -> Decimal {
+    let digits = BigInt::from_bytes_be(Sign::Plus, &raw.to_be_bytes());
+    Decimal::new(digits, contract_decimals as i64)
+}
+
+/// Scale a raw signed amount by the contract decimals into an exact decimal value
+pub fn to_decimal_signed(raw: I256, contract_decimals: i32) -> Decimal {
+    let digits = BigInt::from_signed_bytes_be(&raw.to_be_bytes());
+    Decimal::new(digits, contract_decimals as i64)
+}
+
+/// Parse a raw amount given either as a decimal string or a JSON number
+pub(crate) fn parse_amount<T: FromStr>(value: &str) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    value
+        .parse::<T>()
+        .map_err(|e| format!("invalid token amount {:?}: {}", value, e))
+}
+
+macro_rules! amount_serde {
+    ($module:ident, $ty:ty, $expecting:expr) => {
+        /// Serde helpers which read an amount from a decimal string or number and write it back
+        /// as a decimal string, so no precision is lost when round-tripping
+        pub mod $module {
+            use super::parse_amount;
+            use serde::de::{self, Deserializer, Visitor};
+            use serde::Serializer;
+            use std::fmt;
+
+            pub(crate) struct AmountVisitor;
+
+            impl<'de> Visitor<'de> for AmountVisitor {
+                type Value = $ty;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str($expecting)
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                    parse_amount(v).map_err(E::custom)
+                }
+
+                fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                    parse_amount(&v.to_string()).map_err(E::custom)
+                }
+
+                fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                    parse_amount(&v.to_string()).map_err(E::custom)
+                }
+
+                fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+                    parse_amount(&v.to_string()).map_err(E::custom)
+                }
+
+                fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+                    parse_amount(&v.to_string()).map_err(E::custom)
+                }
+
+                fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                    if v.fract() != 0.0 || v.abs() >= 2f64.powi(53) {
+                        return Err(E::custom(format!(
+                            "token amount {} is not exactly representable",
+                            v
+                        )));
+                    }
+                    parse_amount(&(v as i128).to_string()).map_err(E::custom)
+                }
+            }
+
+            pub fn serialize<S: Serializer>(value: &$ty, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(value)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$ty, D::Error> {
+                deserializer.deserialize_any(AmountVisitor)
+            }
+
+            /// The same as the parent module but for nullable amounts
+            pub mod option {
+                use super::AmountVisitor;
+                use serde::de::{self, Deserializer, Visitor};
+                use serde::Serializer;
+                use std::fmt;
+
+                struct OptionVisitor;
+
+                impl<'de> Visitor<'de> for OptionVisitor {
+                    type Value = Option<$ty>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str($expecting)
+                    }
+
+                    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                        Ok(None)
+                    }
+
+                    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                        Ok(None)
+                    }
+
+                    fn visit_some<D: Deserializer<'de>>(
+                        self,
+                        deserializer: D,
+                    ) -> Result<Self::Value, D::Error> {
+                        deserializer.deserialize_any(AmountVisitor).map(Some)
+                    }
+                }
+
+                pub fn serialize<S: Serializer>(
+                    value: &Option<$ty>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match value {
+                        Some(value) => serializer.collect_str(value),
+                        None => serializer.serialize_none(),
+                    }
+                }
+
+                pub fn deserialize<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Option<$ty>, D::Error> {
+                    deserializer.deserialize_option(OptionVisitor)
+                }
+            }
+        }
+    };
+}
+
+amount_serde!(
+    u256,
+    crate::amount::U256,
+    "an unsigned integer amount as a string or number"
+);
+amount_serde!(
+    i256,
+    crate::amount::I256,
+    "a signed integer amount as a string or number"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Amounts {
+        #[serde(with = "u256")]
+        balance: U256,
+        #[serde(with = "u256::option", default)]
+        balance_24h: Option<U256>,
+        #[serde(with = "i256")]
+        diff: I256,
+    }
+
+    #[test]
+    fn test_amount_round_trip() {
+        let json = r#"{"balance":"115792089237316195423570985008687907853269984665640564039457584007913129639935","balance_24h":null,"diff":"-1000000000000000000000"}"#;
+        let amounts: Amounts = serde_json::from_str(json).unwrap();
+        assert_eq!(U256::MAX, amounts.balance);
+        assert_eq!(None, amounts.balance_24h);
+        assert_eq!(json, serde_json::to_string(&amounts).unwrap());
+    }
+
+    #[test]
+    fn test_amount_from_number() {
+        let amounts: Amounts =
+            serde_json::from_str(r#"{"balance":42,"balance_24h":7,"diff":-3}"#).unwrap();
+        assert_eq!(U256::new(42), amounts.balance);
+        assert_eq!(Some(U256::new(7)), amounts.balance_24h);
+        assert_eq!(I256::new(-3), amounts.diff);
+    }
+
+    #[test]
+    fn test_to_decimal() {
+        let raw = U256::from_str_radix("1234500000000000000000", 10).unwrap();
+        assert_eq!(Decimal::from_str("1234.5").unwrap(), to_decimal(raw, 18));
+        assert_eq!(
+            Decimal::from_str("-0.000000000000000001").unwrap(),
+            to_decimal_signed(I256::new(-1), 18)
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Resolving dates and times to block heights
+//!
+//! The log event and token holder endpoints take block heights, a `BlockResolver` finds the first
+//! block signed at or after a given time using the Get Block Heights endpoint. Resolved heights are
+//! cached so repeated lookups of the same time do not make another API call, which suits taking
+//! balance snapshots at a series of dates with `token_balances_at`.
+//! ```no_run
+//! use covalent_class_a::blocks::parse_date_time;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+//!     let resolver = client.block_resolver();
+//!     let start = parse_date_time("2022-05-18").unwrap();
+//!     let end = parse_date_time("2022-05-20T12:00:00Z").unwrap();
+//!     let (starting_block, ending_block) = resolver.block_range_for_dates(start, end).await.unwrap();
+//! }
+//! ```
+use crate::resources::HistoricalBalancesData;
+use crate::CovalentClient;
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Mutex;
+
+/// Parse either a `YYYY-MM-DD` date, taken as midnight UTC, or an RFC 3339 date time
+pub fn parse_date_time(date_time: &str) -> Result<DateTime<Utc>, Box<dyn Error>> {
+    if let Ok(date) = NaiveDate::parse_from_str(date_time, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+    }
+    match DateTime::parse_from_rfc3339(date_time) {
+        Ok(date_time) => Ok(date_time.with_timezone(&Utc)),
+        Err(e) => Err(format!(
+            "Invalid date {}, expected YYYY-MM-DD or an RFC 3339 date time: {}",
+            date_time, e
+        )
+        .into()),
+    }
+}
+
+/// Resolves times to block heights on the chain of a client, caching the results
+pub struct BlockResolver {
+    client: CovalentClient,
+    cache: Mutex<HashMap<DateTime<Utc>, u64>>,
+}
+
+impl BlockResolver {
+    /// Create a resolver for the chain of a client, any Primer on the client is not used
+    pub fn new(mut client: CovalentClient) -> BlockResolver {
+        client.primer = None;
+        BlockResolver {
+            client,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The height of the first block signed at or after a time
+    pub async fn block_at(&self, date_time: DateTime<Utc>) -> Result<u64, Box<dyn Error>> {
+        if let Some(height) = self.cache.lock().unwrap().get(&date_time) {
+            return Ok(*height);
+        }
+        // the first page of size 1 holds the earliest block between the two times
+        let blocks = self
+            .client
+            .get_block_heights(
+                &date_time.to_rfc3339_opts(SecondsFormat::Secs, true),
+                &(date_time + Duration::days(1)).to_rfc3339_opts(SecondsFormat::Secs, true),
+                Some("1".to_string()),
+                Some("0".to_string()),
+            )
+            .await?;
+        blocks.error.check()?;
+        let height = match blocks.data.items.first() {
+            Some(block) => block.height,
+            None => return Err(format!("No block found signed at or after {}", date_time).into()),
+        };
+        self.cache.lock().unwrap().insert(date_time, height);
+        Ok(height)
+    }
+
+    /// The starting and ending block heights covering the time from start up to end
+    pub async fn block_range_for_dates(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<(u64, u64), Box<dyn Error>> {
+        if end < start {
+            return Err(format!("End date {} is before start date {}", end, start).into());
+        }
+        Ok((self.block_at(start).await?, self.block_at(end).await?))
+    }
+
+    /// Get the token balances of an address as they were at a time, resolving the time to the
+    /// first block signed at or after it
+    pub async fn token_balances_at(
+        &self,
+        addr: &str,
+        date_time: DateTime<Utc>,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<HistoricalBalancesData, Box<dyn Error>> {
+        let block_height = self.block_at(date_time).await?.to_string();
+        self.client
+            .get_historical_token_balances(addr, Some(&block_height), None, page_size, page_number)
+            .await
+    }
+}
+
+impl CovalentClient {
+    /// Create a `BlockResolver` for the chain of this client
+    pub fn block_resolver(&self) -> BlockResolver {
+        BlockResolver::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_date_time() {
+        assert_eq!(
+            Utc.with_ymd_and_hms(2022, 5, 18, 0, 0, 0).unwrap(),
+            parse_date_time("2022-05-18").unwrap()
+        );
+        assert_eq!(
+            Utc.with_ymd_and_hms(2022, 5, 18, 10, 30, 0).unwrap(),
+            parse_date_time("2022-05-18T12:30:00+02:00").unwrap()
+        );
+        assert!(parse_date_time("18/05/2022").is_err());
+    }
+}
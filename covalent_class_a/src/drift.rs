@@ -0,0 +1,379 @@
+//! Detecting drift between API responses and the resource structs
+//!
+//! Covalent adds fields, drops fields and changes their types without notice. With a drift check
+//! set on a client each response is compared against the struct it is decoded into before
+//! decoding, and the differences are reported as diagnostics, either logged as warnings or
+//! collected to be read later.
+//! ```no_run
+//! use covalent_class_a::drift::DriftMode;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217")
+//!         .unwrap()
+//!         .with_drift_check(DriftMode::Collect);
+//!     client
+//!         .get_token_balances("0xf4024faad5fafd0755875e3161524c9c4e1a1111", None, None)
+//!         .await
+//!         .unwrap();
+//!     for report in client.take_drift_reports() {
+//!         println!("{}", report);
+//!     }
+//! }
+//! ```
+use log::warn;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// A resource which keeps the fields it does not model in `extra` maps, its own or those of the
+/// resources it holds
+pub trait Modelled {
+    /// Clear every `extra` map, leaving only the fields the structs model
+    fn clear_extra(&mut self);
+}
+
+impl<T: Modelled> Modelled for Option<T> {
+    fn clear_extra(&mut self) {
+        if let Some(resource) = self {
+            resource.clear_extra();
+        }
+    }
+}
+
+impl<T: Modelled> Modelled for Vec<T> {
+    fn clear_extra(&mut self) {
+        for resource in self {
+            resource.clear_extra();
+        }
+    }
+}
+
+/// What to do with the diagnostics of a response that has drifted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftMode {
+    /// Log each diagnostic as a warning
+    Log,
+    /// Collect the reports, read with `take_drift_reports`
+    Collect,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DriftKind {
+    /// A field in the response which the struct does not model, kept in its `extra` map
+    UnknownField,
+    /// A field the struct models which is not in the response
+    MissingField,
+    /// A field in the response with a different JSON type than the struct models
+    TypeChanged { expected: String, found: String },
+    /// The response could not be decoded into the struct at all
+    Undecodable { message: String },
+}
+
+/// A difference between a response and its struct at a path such as `data.items[].balance`,
+/// array indexes are left out so a field drifting in every item is reported once
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub path: String,
+    #[serde(flatten)]
+    pub kind: DriftKind,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            DriftKind::UnknownField => write!(f, "{}: unknown field", self.path),
+            DriftKind::MissingField => write!(f, "{}: missing field", self.path),
+            DriftKind::TypeChanged { expected, found } => write!(
+                f,
+                "{}: type changed, expected {} but found {}",
+                self.path, expected, found
+            ),
+            DriftKind::Undecodable { message } => {
+                write!(f, "{}: undecodable, {}", self.path, message)
+            }
+        }
+    }
+}
+
+/// The diagnostics of one response
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DriftReport {
+    // the endpoint URL without its query, which holds the API key
+    pub endpoint: String,
+    // the name of the struct the response is decoded into e.g. `BalancesData`
+    pub resource: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} from {}", self.resource, self.endpoint)?;
+        for diagnostic in &self.diagnostics {
+            write!(f, "\n  {}", diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
+/// The drift check set on a client, clones of the client share the collected reports
+#[derive(Debug, Clone)]
+pub struct DriftCheck {
+    pub mode: DriftMode,
+    reports: Arc<Mutex<Vec<DriftReport>>>,
+}
+
+impl DriftCheck {
+    pub fn new(mode: DriftMode) -> DriftCheck {
+        DriftCheck {
+            mode,
+            reports: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    /// Check a response decoded into `T`, logging or collecting a report if it has drifted
+    pub fn check_response<T: DeserializeOwned + Serialize + Modelled>(
+        &self,
+        endpoint: &str,
+        raw: &Value,
+    ) {
+        let diagnostics = check::<T>(raw);
+        if diagnostics.is_empty() {
+            return;
+        }
+        let report = DriftReport {
+            endpoint: endpoint.split('?').next().unwrap_or_default().to_string(),
+            resource: resource_name::<T>(),
+            diagnostics,
+        };
+        match self.mode {
+            DriftMode::Log => warn!("Response has drifted from the struct: {}", report),
+            DriftMode::Collect => self.reports.lock().unwrap().push(report),
+        }
+    }
+
+    /// Take the reports collected so far
+    pub fn take_reports(&self) -> Vec<DriftReport> {
+        std::mem::take(&mut *self.reports.lock().unwrap())
+    }
+}
+
+/// The name of a resource struct without its module path
+fn resource_name<T>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+/// Compare a raw response against the struct `T` it is decoded into
+pub fn check<T: DeserializeOwned + Serialize + Modelled>(raw: &Value) -> Vec<Diagnostic> {
+    let mut resource: T = match serde_path_to_error::deserialize(raw) {
+        Ok(resource) => resource,
+        Err(e) => {
+            return vec![Diagnostic {
+                path: e.path().to_string(),
+                kind: DriftKind::Undecodable {
+                    message: e.inner().to_string(),
+                },
+            }]
+        }
+    };
+    // the fields left in `extra` are the ones the structs do not model
+    resource.clear_extra();
+    let modelled = match serde_json::to_value(&resource) {
+        Ok(modelled) => modelled,
+        Err(e) => {
+            return vec![Diagnostic {
+                path: ".".to_string(),
+                kind: DriftKind::Undecodable {
+                    message: e.to_string(),
+                },
+            }]
+        }
+    };
+    let mut diagnostics = vec![];
+    compare("", raw, &modelled, &mut diagnostics);
+    diagnostics
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn field_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// The modelled field a response field is decoded into, fields named `type` in the API are
+/// modelled with a prefix such as `token_type` as `type` is a keyword
+fn modelled_key<'a>(
+    key: &'a str,
+    raw: &Map<String, Value>,
+    modelled: &'a Map<String, Value>,
+) -> Option<&'a str> {
+    if modelled.contains_key(key) {
+        return Some(key);
+    }
+    if key != "type" {
+        return None;
+    }
+    modelled
+        .keys()
+        .map(String::as_str)
+        .find(|k| k.ends_with("_type") && !raw.contains_key(*k))
+}
+
+fn push(diagnostics: &mut Vec<Diagnostic>, path: String, kind: DriftKind) {
+    let diagnostic = Diagnostic { path, kind };
+    if !diagnostics.contains(&diagnostic) {
+        diagnostics.push(diagnostic);
+    }
+}
+
+fn compare(path: &str, raw: &Value, modelled: &Value, diagnostics: &mut Vec<Diagnostic>) {
+    match (raw, modelled) {
+        (Value::Object(raw), Value::Object(modelled)) => {
+            let mut matched = vec![];
+            for (key, raw_value) in raw {
+                match modelled_key(key, raw, modelled) {
+                    Some(modelled_key) => {
+                        matched.push(modelled_key);
+                        compare(
+                            &field_path(path, key),
+                            raw_value,
+                            &modelled[modelled_key],
+                            diagnostics,
+                        );
+                    }
+                    None => push(diagnostics, field_path(path, key), DriftKind::UnknownField),
+                }
+            }
+            for key in modelled.keys() {
+                if !matched.contains(&key.as_str()) {
+                    push(diagnostics, field_path(path, key), DriftKind::MissingField);
+                }
+            }
+        }
+        (Value::Array(raw), Value::Array(modelled)) => {
+            let path = format!("{}[]", path);
+            for (raw, modelled) in raw.iter().zip(modelled) {
+                compare(&path, raw, modelled, diagnostics);
+            }
+        }
+        // a null on either side is an optional value which is not set
+        (Value::Null, _) | (_, Value::Null) => (),
+        (raw, modelled) if json_type(raw) != json_type(modelled) => push(
+            diagnostics,
+            path.to_string(),
+            DriftKind::TypeChanged {
+                expected: json_type(modelled).to_string(),
+                found: json_type(raw).to_string(),
+            },
+        ),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::{BlockData, LogDecoded, TokenHolderItem};
+
+    #[test]
+    fn test_check_drift() {
+        let json = serde_json::json!({
+            "data": {
+                "updated_at": "2022-05-20T10:38:30Z",
+                "items": [
+                    {"signed_at": "2022-05-18T00:00:01Z", "height": 91321199, "gas_used": 1203451, "base_fee": 25},
+                    {"signed_at": "2022-05-18T00:00:02Z", "height": 91321200, "gas_used": 1203452, "base_fee": 26}
+                ],
+                "has_more": false,
+                "page_number": null,
+                "page_size": null,
+                "total_count": null
+            },
+            "error": false,
+            "error_message": null,
+            "error_code": null
+        });
+        let diagnostics = check::<BlockData>(&json);
+        assert!(diagnostics.contains(&Diagnostic {
+            path: "data.items[].base_fee".to_string(),
+            kind: DriftKind::UnknownField,
+        }));
+        assert!(diagnostics.contains(&Diagnostic {
+            path: "data.items[].block_hash".to_string(),
+            kind: DriftKind::MissingField,
+        }));
+        // each drifted field is reported once however many items it is in
+        assert_eq!(
+            1,
+            diagnostics
+                .iter()
+                .filter(|d| d.path == "data.items[].base_fee")
+                .count()
+        );
+
+        // a resource serializes its unknown fields back out, as the check clears its own copy
+        let block: BlockData = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            json["data"]["items"][0]["base_fee"],
+            serde_json::to_value(&block).unwrap()["data"]["items"][0]["base_fee"]
+        );
+
+        let undecodable = check::<BlockData>(&serde_json::json!({"data": {"items": 5}}));
+        assert_eq!(1, undecodable.len());
+        assert_eq!("data.items", undecodable[0].path);
+    }
+
+    #[test]
+    fn test_check_type_changed() {
+        let json = serde_json::json!({
+            "contract_decimals": 18,
+            "contract_name": "Dai",
+            "contract_ticker_symbol": "DAI",
+            "contract_address": "0x5c74070fdea071359b86082bd9f9b3deaafbe32b",
+            "supports_erc": null,
+            "logo_url": "",
+            "address": "0xf4024faad5fafd0755875e3161524c9c4e1a1111",
+            "balance": 500,
+            "total_supply": "1000",
+            "block_height": 91321208
+        });
+        assert_eq!(
+            vec![Diagnostic {
+                path: "balance".to_string(),
+                kind: DriftKind::TypeChanged {
+                    expected: "string".to_string(),
+                    found: "number".to_string(),
+                },
+            }],
+            check::<TokenHolderItem>(&json)
+        );
+    }
+
+    #[test]
+    fn test_check_renamed_type() {
+        let json = serde_json::json!({
+            "name": "Transfer",
+            "signature": "Transfer(uint256 value)",
+            "params": [{"name": "value", "type": "uint256", "indexed": false, "decoded": true, "value": "5"}]
+        });
+        assert_eq!(Vec::<Diagnostic>::new(), check::<LogDecoded>(&json));
+    }
+}
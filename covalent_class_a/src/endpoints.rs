@@ -0,0 +1,298 @@
+// @generated by `cargo run --manifest-path xtask/Cargo.toml -- codegen` from
+// spec/covalent.json and spec/overrides.json, edit those rather than this file
+//! The endpoints of the API as described by the vendored spec
+
+/// An endpoint of the API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endpoint {
+    pub operation_id: &'static str,
+    pub summary: &'static str,
+    // relative to the base URL with `{name}` placeholders for the path parameters
+    pub path: &'static str,
+    pub path_params: &'static [&'static str],
+    pub query_params: &'static [&'static str],
+    // the resource struct the response is decoded into
+    pub resource: &'static str,
+}
+
+/// Find an endpoint by its operation ID e.g. `getTokenBalances`
+pub fn endpoint(operation_id: &str) -> Option<&'static Endpoint> {
+    ENDPOINTS
+        .iter()
+        .find(|endpoint| endpoint.operation_id == operation_id)
+}
+
+pub const ENDPOINTS: &[Endpoint] = &[
+    Endpoint {
+        operation_id: "getTokenBalances",
+        summary: "Get token balances for an address",
+        path: "/{chain_id}/address/{address}/balances_v2/",
+        path_params: &["chain_id", "address"],
+        query_params: &["nft", "no-nft-fetch", "page-size", "page-number"],
+        resource: "BalancesData",
+    },
+    Endpoint {
+        operation_id: "getHistoricalTokenBalances",
+        summary: "Get token balances for an address at a block height or date",
+        path: "/{chain_id}/address/{address}/historical_balances/",
+        path_params: &["chain_id", "address"],
+        query_params: &["block-height", "date", "page-size", "page-number"],
+        resource: "HistoricalBalancesData",
+    },
+    Endpoint {
+        operation_id: "getHistoricalPortfolioValue",
+        summary: "Get the historical portfolio value of an address",
+        path: "/{chain_id}/address/{address}/portfolio_v2/",
+        path_params: &["chain_id", "address"],
+        query_params: &["page-size", "page-number"],
+        resource: "HistoricalPortfolioData",
+    },
+    Endpoint {
+        operation_id: "getTokenTransfers",
+        summary: "Get ERC20 token transfers of an address",
+        path: "/{chain_id}/address/{address}/transfers_v2/",
+        path_params: &["chain_id", "address"],
+        query_params: &["contract-address", "page-size", "page-number"],
+        resource: "TokenTransfersData",
+    },
+    Endpoint {
+        operation_id: "getTokenHolders",
+        summary: "Get the holders of a token at a block height",
+        path: "/{chain_id}/tokens/{address}/token_holders/",
+        path_params: &["chain_id", "address"],
+        query_params: &["block-height", "page-size", "page-number"],
+        resource: "TokenHoldersData",
+    },
+    Endpoint {
+        operation_id: "getChangesInTokenHolders",
+        summary: "Get changes in token holders between two block heights",
+        path: "/{chain_id}/tokens/{address}/token_holders_changes/",
+        path_params: &["chain_id", "address"],
+        query_params: &["starting-block", "ending-block", "page-size", "page-number"],
+        resource: "ChangesInTokenHoldersData",
+    },
+    Endpoint {
+        operation_id: "getTransactionsForAddress",
+        summary: "Get transactions of an address",
+        path: "/{chain_id}/address/{address}/transactions_v2/",
+        path_params: &["chain_id", "address"],
+        query_params: &["page-size", "page-number"],
+        resource: "TransactionsData",
+    },
+    Endpoint {
+        operation_id: "getTransactionsForAddressV3",
+        summary: "Get the most recent page of transactions of an address",
+        path: "/{chain_id}/address/{address}/transactions_v3/",
+        path_params: &["chain_id", "address"],
+        query_params: &["page-size", "page-number"],
+        resource: "TransactionsV3Data",
+    },
+    Endpoint {
+        operation_id: "getTransactionsForAddressV3Page",
+        summary: "Get a page of transactions of an address",
+        path: "/{chain_id}/address/{address}/transactions_v3/page/{page}/",
+        path_params: &["chain_id", "address", "page"],
+        query_params: &["page-size", "page-number"],
+        resource: "TransactionsV3Data",
+    },
+    Endpoint {
+        operation_id: "getTransactionSummary",
+        summary: "Get the transaction count and earliest and latest transactions of an address",
+        path: "/{chain_id}/address/{address}/transactions_summary/",
+        path_params: &["chain_id", "address"],
+        query_params: &["page-size", "page-number"],
+        resource: "TransactionSummaryData",
+    },
+    Endpoint {
+        operation_id: "getTransaction",
+        summary: "Get a single transaction",
+        path: "/{chain_id}/transaction_v2/{tx_hash}/",
+        path_params: &["chain_id", "tx_hash"],
+        query_params: &["page-size", "page-number"],
+        resource: "TransactionData",
+    },
+    Endpoint {
+        operation_id: "getBlock",
+        summary: "Get a single block",
+        path: "/{chain_id}/block_v2/{block_height}/",
+        path_params: &["chain_id", "block_height"],
+        query_params: &["page-size", "page-number"],
+        resource: "BlockData",
+    },
+    Endpoint {
+        operation_id: "getBlockTransactions",
+        summary: "Get the most recent page of transactions of a block",
+        path: "/{chain_id}/block/{block_height}/transactions_v3/",
+        path_params: &["chain_id", "block_height"],
+        query_params: &["page-size", "page-number"],
+        resource: "BlockTransactionsData",
+    },
+    Endpoint {
+        operation_id: "getBlockTransactionsPage",
+        summary: "Get a page of transactions of a block",
+        path: "/{chain_id}/block/{block_height}/transactions_v3/page/{page}/",
+        path_params: &["chain_id", "block_height", "page"],
+        query_params: &["page-size", "page-number"],
+        resource: "BlockTransactionsData",
+    },
+    Endpoint {
+        operation_id: "getBlockHeights",
+        summary: "Get the block heights between two dates",
+        path: "/{chain_id}/block_v2/{start_date}/{end_date}/",
+        path_params: &["chain_id", "start_date", "end_date"],
+        query_params: &["page-size", "page-number"],
+        resource: "BlockData",
+    },
+    Endpoint {
+        operation_id: "getLogEventsByContract",
+        summary: "Get the log events emitted by a contract",
+        path: "/{chain_id}/events/address/{address}/",
+        path_params: &["chain_id", "address"],
+        query_params: &["starting-block", "ending-block", "page-size", "page-number"],
+        resource: "LogEventsGenericData",
+    },
+    Endpoint {
+        operation_id: "getLogEventsByTopicHashes",
+        summary: "Get log events by topic hash",
+        path: "/{chain_id}/events/topics/{topic}/",
+        path_params: &["chain_id", "topic"],
+        query_params: &[
+            "starting-block",
+            "ending-block",
+            "sender-address",
+            "secondary-topics",
+            "page-size",
+            "page-number",
+        ],
+        resource: "LogEventsGenericData",
+    },
+    Endpoint {
+        operation_id: "getAllContractMetadata",
+        summary: "Get the metadata of all contracts",
+        path: "/{chain_id}/tokens/tokenlists/all/",
+        path_params: &["chain_id"],
+        query_params: &["page-size", "page-number"],
+        resource: "ContractMetadataData",
+    },
+    Endpoint {
+        operation_id: "getNftTokenIds",
+        summary: "Get the token IDs of an NFT contract",
+        path: "/{chain_id}/tokens/{contract_address}/nft_token_ids/",
+        path_params: &["chain_id", "contract_address"],
+        query_params: &["page-size", "page-number"],
+        resource: "NftTokenIdsData",
+    },
+    Endpoint {
+        operation_id: "getNftTransactions",
+        summary: "Get the transactions of an NFT",
+        path: "/{chain_id}/tokens/{contract_address}/nft_transactions/{token_id}/",
+        path_params: &["chain_id", "contract_address", "token_id"],
+        query_params: &["page-size", "page-number"],
+        resource: "NftTransactionsData",
+    },
+    Endpoint {
+        operation_id: "getNftExternalMetadata",
+        summary: "Get the external metadata of an NFT",
+        path: "/{chain_id}/tokens/{contract_address}/nft_metadata/{token_id}/",
+        path_params: &["chain_id", "contract_address", "token_id"],
+        query_params: &["page-size", "page-number"],
+        resource: "NftMetadataData",
+    },
+    Endpoint {
+        operation_id: "getHistoricalTokenPrices",
+        summary: "Get the historical prices of tokens",
+        path:
+            "/pricing/historical_by_addresses_v2/{chain_id}/{quote_currency}/{contract_addresses}/",
+        path_params: &["chain_id", "quote_currency", "contract_addresses"],
+        query_params: &["from", "to", "page-size", "page-number"],
+        resource: "HistoricalPricesData",
+    },
+    Endpoint {
+        operation_id: "getSpotPrices",
+        summary: "Get the spot prices of tokens by ticker",
+        path: "/pricing/tickers/",
+        path_params: &[],
+        query_params: &["tickers", "page-size", "page-number"],
+        resource: "SpotPricesData",
+    },
+    Endpoint {
+        operation_id: "getXykPools",
+        summary: "Get the pools of a DEX",
+        path: "/{chain_id}/xy=k/{dex_name}/pools/",
+        path_params: &["chain_id", "dex_name"],
+        query_params: &["page-size", "page-number"],
+        resource: "XykPoolsData",
+    },
+    Endpoint {
+        operation_id: "getXykPoolByAddress",
+        summary: "Get a pool of a DEX by its address",
+        path: "/{chain_id}/xy=k/{dex_name}/pools/address/{pool_address}/",
+        path_params: &["chain_id", "dex_name", "pool_address"],
+        query_params: &["page-size", "page-number"],
+        resource: "XykPoolsData",
+    },
+    Endpoint {
+        operation_id: "getXykAddressExchangeBalances",
+        summary: "Get the exchange balances of an address on a DEX",
+        path: "/{chain_id}/xy=k/{dex_name}/address/{address}/balances/",
+        path_params: &["chain_id", "dex_name", "address"],
+        query_params: &["page-size", "page-number"],
+        resource: "XykAddressBalancesData",
+    },
+    Endpoint {
+        operation_id: "getXykPoolTransactions",
+        summary: "Get the transactions of a pool of a DEX",
+        path: "/{chain_id}/xy=k/{dex_name}/pools/address/{pool_address}/transactions/",
+        path_params: &["chain_id", "dex_name", "pool_address"],
+        query_params: &["page-size", "page-number"],
+        resource: "XykTransactionsData",
+    },
+    Endpoint {
+        operation_id: "getXykEcosystemChart",
+        summary: "Get the volume and liquidity charts of a DEX",
+        path: "/{chain_id}/xy=k/{dex_name}/ecosystem/",
+        path_params: &["chain_id", "dex_name"],
+        query_params: &["page-size", "page-number"],
+        resource: "XykEcosystemData",
+    },
+    Endpoint {
+        operation_id: "getGasPrices",
+        summary: "Get gas prices for an event type",
+        path: "/{chain_id}/event/{event_type}/gas_prices/",
+        path_params: &["chain_id", "event_type"],
+        query_params: &["quote-currency", "page-size", "page-number"],
+        resource: "GasPricesData",
+    },
+    Endpoint {
+        operation_id: "getResolvedAddress",
+        summary: "Get the address a name-service domain resolves to",
+        path: "/{chain_id}/address/{name}/resolve_address/",
+        path_params: &["chain_id", "name"],
+        query_params: &["page-size", "page-number"],
+        resource: "ResolvedAddressData",
+    },
+    Endpoint {
+        operation_id: "getAddressActivity",
+        summary: "Get the chains an address has activity on",
+        path: "/address/{address}/activity/",
+        path_params: &["address"],
+        query_params: &["page-size", "page-number"],
+        resource: "AddressActivityData",
+    },
+    Endpoint {
+        operation_id: "getAllChains",
+        summary: "Get all chains",
+        path: "/chains/",
+        path_params: &[],
+        query_params: &["quote-currency", "page-size", "page-number"],
+        resource: "AllChainData",
+    },
+    Endpoint {
+        operation_id: "getAllChainStatuses",
+        summary: "Get the statuses of all chains",
+        path: "/chains/status/",
+        path_params: &[],
+        query_params: &["quote-currency", "page-size", "page-number"],
+        resource: "AllChainStatusesData",
+    },
+];
@@ -0,0 +1,271 @@
+//! A faster path for decoding big payloads
+//!
+//! `from_slice` decodes JSON with simd-json when the `simd-json` feature is enabled, or with
+//! serde_json otherwise, and every `get_*` method decodes its response with simd-json too when the
+//! feature is enabled. The `*Ref` types
+//! are borrowed variants of the hot resource types such as `LogEventItem` and `BaseTransaction`,
+//! whose hashes, addresses and timestamps borrow from the payload rather than each being copied
+//! into a `String`. They leave out the `extra` map of unknown fields, and keep timestamps as the
+//! raw RFC 3339 strings.
+//! ```no_run
+//! use covalent_class_a::fast::{self, TransactionsDataRef};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+//!     let mut json = client
+//!         .get_raw_bytes("address/0xf4024faad5fafd0755875e3161524c9c4e1a1111/transactions_v2/", &[], None, None)
+//!         .await
+//!         .unwrap();
+//!     let transactions: TransactionsDataRef = fast::from_slice(&mut json).unwrap();
+//!     for transaction in transactions.data.items {
+//!         println!("{}", transaction.transaction.tx_hash);
+//!     }
+//! }
+//! ```
+use crate::amount::{self, U256};
+use crate::resources::{ApiError, ApiPagination, LogDecoded};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::borrow::Cow;
+use std::error::Error;
+
+/// Decode JSON, with simd-json if the `simd-json` feature is enabled. simd-json parses in place
+/// so the bytes are taken mutably, and what is decoded can borrow strings from them
+pub fn from_slice<'a, T: Deserialize<'a>>(json: &'a mut [u8]) -> Result<T, Box<dyn Error>> {
+    #[cfg(feature = "simd-json")]
+    return Ok(simd_json::serde::from_slice(json)?);
+    #[cfg(not(feature = "simd-json"))]
+    Ok(serde_json::from_slice(json)?)
+}
+
+// hex strings such as hashes and addresses never hold escapes so are borrowed as `&str`, while
+// text such as labels is a `Cow` as serde_json can only borrow strings without escapes
+
+/// Deserialize an optional `Cow`, borrowing the string where it can which serde only does for a
+/// `Cow` outside of an `Option`
+fn optional_cow<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Cow<'a, str>>, D::Error> {
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+    Ok(Option::<Borrowed>::deserialize(deserializer)?.map(|borrowed| borrowed.0))
+}
+
+/// A borrowed `LogEventItem`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct LogEventItemRef<'a> {
+    pub block_signed_at: &'a str,
+    pub block_height: u64,
+    pub tx_offset: u64,
+    pub log_offset: u64,
+    pub tx_hash: &'a str,
+    #[serde(borrow)]
+    pub raw_log_topics: Option<Vec<&'a str>>,
+    pub sender_contract_decimals: i32,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    pub sender_name: Option<Cow<'a, str>>,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    pub sender_contract_ticker_symbol: Option<Cow<'a, str>>,
+    pub sender_address: &'a str,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    pub sender_address_label: Option<Cow<'a, str>>,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    pub sender_logo_url: Option<Cow<'a, str>>,
+    pub raw_log_data: Option<&'a str>,
+    pub decoded: Option<LogDecoded>,
+}
+
+/// A borrowed `BaseTransaction`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BaseTransactionRef<'a> {
+    pub block_signed_at: &'a str,
+    pub block_height: u64,
+    pub tx_hash: &'a str,
+    pub tx_offset: u64,
+    pub successful: bool,
+    pub from_address: &'a str,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    pub from_address_label: Option<Cow<'a, str>>,
+    pub to_address: &'a str,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    pub to_address_label: Option<Cow<'a, str>>,
+    #[serde(with = "amount::u256")]
+    pub value: U256,
+    pub value_quote: f64,
+    pub gas_offered: u64,
+    pub gas_spent: u64,
+    pub gas_price: u64,
+    #[serde(with = "amount::u256::option", default)]
+    pub fees_paid: Option<U256>,
+    pub gas_quote: f64,
+    pub gas_quote_rate: f64,
+}
+
+/// A borrowed `BlockTransactionWithLogEvents`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "RawBlockTransactionRef")]
+pub struct BlockTransactionRef<'a> {
+    #[serde(flatten, borrow)]
+    pub transaction: BaseTransactionRef<'a>,
+    #[serde(borrow)]
+    pub log_events: Option<Vec<LogEventItemRef<'a>>>,
+}
+
+// the transaction is read flat and then split out of it, as a flattened field is buffered before
+// being decoded which costs more than the borrowing saves
+#[derive(Deserialize)]
+struct RawBlockTransactionRef<'a> {
+    block_signed_at: &'a str,
+    block_height: u64,
+    tx_hash: &'a str,
+    tx_offset: u64,
+    successful: bool,
+    from_address: &'a str,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    from_address_label: Option<Cow<'a, str>>,
+    to_address: &'a str,
+    #[serde(borrow, deserialize_with = "optional_cow", default)]
+    to_address_label: Option<Cow<'a, str>>,
+    #[serde(with = "amount::u256")]
+    value: U256,
+    value_quote: f64,
+    gas_offered: u64,
+    gas_spent: u64,
+    gas_price: u64,
+    #[serde(with = "amount::u256::option", default)]
+    fees_paid: Option<U256>,
+    gas_quote: f64,
+    gas_quote_rate: f64,
+    #[serde(borrow)]
+    log_events: Option<Vec<LogEventItemRef<'a>>>,
+}
+
+impl<'a> From<RawBlockTransactionRef<'a>> for BlockTransactionRef<'a> {
+    fn from(raw: RawBlockTransactionRef<'a>) -> Self {
+        BlockTransactionRef {
+            transaction: BaseTransactionRef {
+                block_signed_at: raw.block_signed_at,
+                block_height: raw.block_height,
+                tx_hash: raw.tx_hash,
+                tx_offset: raw.tx_offset,
+                successful: raw.successful,
+                from_address: raw.from_address,
+                from_address_label: raw.from_address_label,
+                to_address: raw.to_address,
+                to_address_label: raw.to_address_label,
+                value: raw.value,
+                value_quote: raw.value_quote,
+                gas_offered: raw.gas_offered,
+                gas_spent: raw.gas_spent,
+                gas_price: raw.gas_price,
+                fees_paid: raw.fees_paid,
+                gas_quote: raw.gas_quote,
+                gas_quote_rate: raw.gas_quote_rate,
+            },
+            log_events: raw.log_events,
+        }
+    }
+}
+
+/// A borrowed `Transactions`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TransactionsRef<'a> {
+    pub address: &'a str,
+    pub updated_at: &'a str,
+    pub next_update_at: &'a str,
+    pub quote_currency: &'a str,
+    pub chain_id: i64,
+    #[serde(borrow)]
+    pub items: Vec<BlockTransactionRef<'a>>,
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+}
+
+/// A borrowed `TransactionsData`, the response of Get Transactions for Address
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TransactionsDataRef<'a> {
+    #[serde(borrow)]
+    pub data: TransactionsRef<'a>,
+    #[serde(flatten)]
+    pub error: ApiError,
+}
+
+/// A borrowed `LogEventsGeneric`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct LogEventsRef<'a> {
+    pub updated_at: &'a str,
+    #[serde(borrow)]
+    pub items: Vec<LogEventItemRef<'a>>,
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+}
+
+/// A borrowed `LogEventsGenericData`, the response of the log event endpoints
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct LogEventsDataRef<'a> {
+    #[serde(borrow)]
+    pub data: Option<LogEventsRef<'a>>,
+    #[serde(flatten)]
+    pub error: ApiError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::{ParamValue, TransactionsData};
+
+    const TRANSACTIONS: &str = include_str!("../benches/fixtures/transactions.json");
+
+    #[test]
+    fn test_from_slice_borrowed() {
+        let owned: TransactionsData = serde_json::from_str(TRANSACTIONS).unwrap();
+        let mut json = TRANSACTIONS.as_bytes().to_vec();
+        let borrowed: TransactionsDataRef = from_slice(&mut json).unwrap();
+
+        assert_eq!(owned.data.items.len(), borrowed.data.items.len());
+        for (owned, borrowed) in owned.data.items.iter().zip(&borrowed.data.items) {
+            assert_eq!(owned.transaction.tx_hash, borrowed.transaction.tx_hash);
+            assert_eq!(owned.transaction.value, borrowed.transaction.value);
+            assert_eq!(owned.transaction.fees_paid, borrowed.transaction.fees_paid);
+            assert_eq!(
+                owned.transaction.to_address_label.as_deref(),
+                borrowed.transaction.to_address_label.as_deref()
+            );
+            let owned_events = owned.log_events.as_deref().unwrap_or_default();
+            let borrowed_events = borrowed.log_events.as_deref().unwrap_or_default();
+            assert_eq!(owned_events.len(), borrowed_events.len());
+            for (owned, borrowed) in owned_events.iter().zip(borrowed_events) {
+                assert_eq!(
+                    owned.raw_log_topics.as_ref().unwrap(),
+                    borrowed.raw_log_topics.as_ref().unwrap()
+                );
+                assert_eq!(owned.decoded, borrowed.decoded);
+            }
+        }
+        let first_event = &borrowed.data.items[0].log_events.as_ref().unwrap()[0];
+        assert!(matches!(first_event.sender_name, Some(Cow::Borrowed(_))));
+        // a label with escapes is unescaped into an owned string by serde_json
+        assert_eq!(
+            Some("Dai \"Stablecoin\" Token"),
+            borrowed.data.items[1]
+                .transaction
+                .to_address_label
+                .as_deref()
+        );
+        assert_eq!(
+            Some(&ParamValue::Uint(U256::new(1_000_000_000_000_000_000))),
+            first_event.decoded.as_ref().unwrap().param("value")
+        );
+    }
+
+    #[test]
+    fn test_from_slice_owned() {
+        let mut json = TRANSACTIONS.as_bytes().to_vec();
+        let owned: TransactionsData = from_slice(&mut json).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TransactionsData>(TRANSACTIONS).unwrap(),
+            owned
+        );
+        assert!(from_slice::<TransactionsData>(&mut b"{\"data\": 5}".to_vec()).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+//! Complete snapshots of token holders
+//!
+//! The Get Token Holders endpoint returns one page of holders at a time. `holders_snapshot` walks
+//! every page at a block height into a single list with each holder address appearing once, which
+//! can be exported to CSV or JSON with exact balances.
+//! ```no_run
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+//!     let snapshot = client
+//!         .holders_snapshot("0x5c74070fdea071359b86082bd9f9b3deaafbe32b", Some(91321208))
+//!         .await
+//!         .unwrap();
+//!     std::fs::write("holders.csv", snapshot.to_csv().unwrap()).unwrap();
+//! }
+//! ```
+use crate::amount::U256;
+use crate::resources::TokenHolderItem;
+use crate::CovalentClient;
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::error::Error;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HoldersSnapshot {
+    pub contract_address: String,
+    // the block height requested, or the height the API returned the latest holders at
+    pub block_height: Option<u64>,
+    pub holders: Vec<TokenHolderItem>,
+}
+
+/// A row of the CSV export
+#[derive(Serialize)]
+struct HolderRow<'a> {
+    address: &'a str,
+    #[serde(with = "crate::amount::u256")]
+    balance: U256,
+    balance_decimal: String,
+    contract_decimals: i32,
+    block_height: u64,
+}
+
+impl HoldersSnapshot {
+    /// Build a snapshot from pages of holders, keeping the first occurrence of each holder address
+    pub fn new(
+        contract_address: &str,
+        block_height: Option<u64>,
+        holders: impl IntoIterator<Item = TokenHolderItem>,
+    ) -> HoldersSnapshot {
+        let mut seen = HashSet::new();
+        let holders: Vec<TokenHolderItem> = holders
+            .into_iter()
+            .filter(|holder| seen.insert(holder.address.to_lowercase()))
+            .collect();
+        let block_height = block_height.or_else(|| holders.first().map(|h| h.block_height));
+        HoldersSnapshot {
+            contract_address: contract_address.to_string(),
+            block_height,
+            holders,
+        }
+    }
+
+    /// The sum of the balances of all holders, `None` if it overflows a `U256`
+    pub fn total_balance(&self) -> Option<U256> {
+        self.holders.iter().try_fold(U256::ZERO, |total, holder| {
+            total.checked_add(holder.balance)
+        })
+    }
+
+    /// Export the holders as CSV with a header row, balances are exact decimal strings
+    pub fn to_csv(&self) -> Result<String, Box<dyn Error>> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        for holder in &self.holders {
+            writer.serialize(HolderRow {
+                address: &holder.address,
+                balance: holder.balance,
+                balance_decimal: holder.balance_decimal().to_string(),
+                contract_decimals: holder.contract_decimals,
+                block_height: holder.block_height,
+            })?;
+        }
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    /// Export the snapshot as pretty JSON, balances are exact decimal strings
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl CovalentClient {
+    /// Get every holder of a token at a block height, or the latest holders if no height is given
+    pub async fn holders_snapshot(
+        &self,
+        token_addr: &str,
+        block_height: Option<u64>,
+    ) -> Result<HoldersSnapshot, Box<dyn Error>> {
+        let height = block_height.map(|height| height.to_string());
+        let holders: Vec<TokenHolderItem> = self
+            .stream_token_holders(token_addr, height.as_deref(), None)
+            .try_collect()
+            .await?;
+        Ok(HoldersSnapshot::new(token_addr, block_height, holders))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holder(address: &str, balance: u128) -> TokenHolderItem {
+        TokenHolderItem {
+            contract_decimals: 2,
+            contract_name: "Dai".to_string(),
+            contract_ticker_symbol: "DAI".to_string(),
+            contract_address: "0x5c74070fdea071359b86082bd9f9b3deaafbe32b".to_string(),
+            supports_erc: None,
+            logo_url: String::new(),
+            address: address.to_string(),
+            balance: U256::new(balance),
+            total_supply: U256::new(1000),
+            block_height: 91321208,
+            extra: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_holders_snapshot() {
+        // a holder repeated across the boundary of two pages appears once
+        let pages = vec![
+            holder("0xaa", 500),
+            holder("0xbb", 250),
+            holder("0xAA", 500),
+        ];
+        let snapshot =
+            HoldersSnapshot::new("0x5c74070fdea071359b86082bd9f9b3deaafbe32b", None, pages);
+        assert_eq!(2, snapshot.holders.len());
+        assert_eq!(Some(91321208), snapshot.block_height);
+        assert_eq!(Some(U256::new(750)), snapshot.total_balance());
+        assert_eq!(
+            "address,balance,balance_decimal,contract_decimals,block_height\n0xaa,500,5.00,2,91321208\n0xbb,250,2.50,2,91321208\n",
+            snapshot.to_csv().unwrap()
+        );
+
+        let mut whale = holder("0xcc", 0);
+        whale.balance = U256::MAX;
+        let overflowing = HoldersSnapshot::new(
+            "0x5c74070fdea071359b86082bd9f9b3deaafbe32b",
+            None,
+            vec![holder("0xaa", 1), whale],
+        );
+        assert_eq!(None, overflowing.total_balance());
+    }
+}
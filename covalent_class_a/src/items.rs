@@ -0,0 +1,388 @@
+//! Decoding the items of a response while it is still downloading
+//!
+//! The `get_*` methods buffer a whole response before decoding it, which for a big page of
+//! transactions with their log events or of all contract metadata holds the body and every
+//! decoded item in memory at once. The `get_*_items` methods here instead scan the body as it
+//! arrives and decode each item of `data.items` as soon as it is complete, yielding them as a
+//! stream. Everything outside of the items is kept to check the error flagged in the response
+//! once the body ends. Responses decoded this way are not checked for drift.
+//! ```no_run
+//! use futures::TryStreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+//!     let mut transactions = Box::pin(client.get_transactions_for_address_items(
+//!         "0xf4024faad5fafd0755875e3161524c9c4e1a1111",
+//!         Some("1000".to_string()),
+//!         None,
+//!     ));
+//!     while let Some(transaction) = transactions.try_next().await.unwrap() {
+//!         println!("{}", transaction.transaction.tx_hash);
+//!     }
+//! }
+//! ```
+use crate::resources::{
+    ApiError, BlockTransactionWithLogEvents, ContractMetadataItem, LogEventItem, TokenHolderItem,
+    WalletBalanceItem,
+};
+use crate::{add_pagination_params, CovalentClient};
+use futures::stream::{self, Stream, TryStreamExt};
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use std::error::Error;
+
+/// An object or array the scanner is inside of
+struct Container {
+    object: bool,
+    // the key of the value being read, if this is an object
+    key: Vec<u8>,
+}
+
+/// Splits the items out of `data.items` in a JSON body fed to it in chunks
+#[derive(Default)]
+struct ItemScanner {
+    stack: Vec<Container>,
+    in_string: bool,
+    escaped: bool,
+    // the last string read, which is a key if a `:` follows it
+    string: Vec<u8>,
+    // the depth of the items list while inside it
+    items_depth: Option<usize>,
+    found_items: bool,
+    // the bytes of the item being read
+    item: Vec<u8>,
+    // the bytes outside of the items list, with the list left empty
+    envelope: Vec<u8>,
+}
+
+impl ItemScanner {
+    /// Feed the next chunk of the body, returning the items completed in it
+    fn feed(&mut self, chunk: &[u8]) -> Vec<Vec<u8>> {
+        let mut items = vec![];
+        for &byte in chunk {
+            if let Some(item) = self.push(byte) {
+                items.push(item);
+            }
+        }
+        items
+    }
+
+    fn push(&mut self, byte: u8) -> Option<Vec<u8>> {
+        let depth = match self.items_depth {
+            Some(depth) if self.stack.len() >= depth => depth,
+            _ => {
+                self.envelope.push(byte);
+                self.track(byte);
+                return None;
+            }
+        };
+        // a `,` or `]` directly inside the list ends the item being read
+        if self.stack.len() == depth && !self.in_string && matches!(byte, b',' | b']') {
+            let item = std::mem::take(&mut self.item);
+            if byte == b']' {
+                self.items_depth = None;
+                self.envelope.push(byte);
+                self.track(byte);
+            }
+            return (!item.is_empty()).then_some(item);
+        }
+        if !(self.item.is_empty() && byte.is_ascii_whitespace()) {
+            self.item.push(byte);
+        }
+        self.track(byte);
+        None
+    }
+
+    /// Follow strings and the nesting of objects and arrays
+    fn track(&mut self, byte: u8) {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            } else if self.stack.len() <= 2 {
+                // only the keys of `data` and its parent lead to the items
+                self.string.push(byte);
+            }
+            return;
+        }
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                self.string.clear();
+            }
+            b':' => {
+                if let Some(container) = self.stack.last_mut().filter(|c| c.object) {
+                    container.key = std::mem::take(&mut self.string);
+                }
+            }
+            b'{' | b'[' => {
+                let items = byte == b'['
+                    && matches!(
+                        self.stack.as_slice(),
+                        [root, data] if root.object && root.key == b"data"
+                            && data.object && data.key == b"items"
+                    );
+                self.stack.push(Container {
+                    object: byte == b'{',
+                    key: vec![],
+                });
+                if items {
+                    self.items_depth = Some(self.stack.len());
+                    self.found_items = true;
+                }
+            }
+            b'}' | b']' => {
+                self.stack.pop();
+            }
+            _ => (),
+        }
+    }
+
+    /// Check the body was complete, had an items list and flagged no error
+    fn finish(&self) -> Result<(), Box<dyn Error>> {
+        if !self.stack.is_empty() || self.in_string {
+            return Err("The response ended before its JSON was complete".into());
+        }
+        let error: ApiError = serde_json::from_slice(&self.envelope)
+            .map_err(|e| format!("Failed to decode the response: {}", e))?;
+        error.check()?;
+        if !self.found_items {
+            return Err("The response has no data.items list".into());
+        }
+        Ok(())
+    }
+}
+
+/// Decode the items of a JSON body as its chunks arrive
+pub fn decode_items<'a, T, S, B>(chunks: S) -> impl Stream<Item = Result<T, Box<dyn Error>>> + 'a
+where
+    T: DeserializeOwned + 'a,
+    S: Stream<Item = Result<B, Box<dyn Error>>> + 'a,
+    B: AsRef<[u8]>,
+{
+    let chunks = Box::pin(chunks);
+    stream::try_unfold(
+        (chunks, ItemScanner::default(), false),
+        |(mut chunks, mut scanner, done)| async move {
+            if done {
+                return Ok::<_, Box<dyn Error>>(None);
+            }
+            let items: Vec<Result<T, Box<dyn Error>>> = match chunks.try_next().await? {
+                Some(chunk) => scanner
+                    .feed(chunk.as_ref())
+                    .iter()
+                    .map(|item| Ok(serde_json::from_slice(item)?))
+                    .collect(),
+                None => {
+                    scanner.finish()?;
+                    return Ok(Some((stream::iter(vec![]), (chunks, scanner, true))));
+                }
+            };
+            Ok(Some((stream::iter(items), (chunks, scanner, false))))
+        },
+    )
+    .try_flatten()
+}
+
+/// The chunks of a response body as they are downloaded
+fn response_chunks(resp: Response) -> impl Stream<Item = Result<Vec<u8>, Box<dyn Error>>> {
+    stream::try_unfold(resp, |mut resp| async move {
+        let chunk = resp.chunk().await?;
+        Ok(chunk.map(|chunk| (chunk.to_vec(), resp)))
+    })
+}
+
+impl CovalentClient {
+    /// Request an endpoint and decode its items as the response downloads
+    fn items_at<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        endpoint: String,
+    ) -> impl Stream<Item = Result<T, Box<dyn Error>>> + 'a {
+        stream::once(async move { self.make_request(&endpoint).await })
+            .map_ok(|resp| decode_items(response_chunks(resp)))
+            .try_flatten()
+    }
+
+    /// Stream the items of any endpoint, given its path relative to the chain of the client, as
+    /// the response downloads. Extra query parameters are given as name and value pairs
+    pub fn get_items<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        path: &str,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> impl Stream<Item = Result<T, Box<dyn Error>>> + 'a {
+        self.items_at(self.path_endpoint(path, None, params, page_size, page_number))
+    }
+
+    /// Stream the token balances of an address as the response downloads
+    pub fn get_token_balances_items<'a>(
+        &'a self,
+        addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> impl Stream<Item = Result<WalletBalanceItem, Box<dyn Error>>> + 'a {
+        let mut endpoint = format!(
+            "{}/{}/address/{}/balances_v2/?key={}",
+            self.base_url, self.chain_id, addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+        self.items_at(endpoint)
+    }
+
+    /// Stream the token holders of a token at a block height, or the latest holders if no block
+    /// height is given, as the response downloads
+    pub fn get_token_holders_any_bh_items<'a>(
+        &'a self,
+        addr: &str,
+        block_height: Option<&str>,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> impl Stream<Item = Result<TokenHolderItem, Box<dyn Error>>> + 'a {
+        let mut endpoint = format!(
+            "{}/{}/tokens/{}/token_holders/?key={}",
+            self.base_url, self.chain_id, addr, self.api_key
+        );
+        if let Some(block_height) = block_height {
+            endpoint = format!("{}&block-height={}", endpoint, block_height)
+        }
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+        self.items_at(endpoint)
+    }
+
+    /// Stream the transactions of an address as the response downloads
+    pub fn get_transactions_for_address_items<'a>(
+        &'a self,
+        addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> impl Stream<Item = Result<BlockTransactionWithLogEvents, Box<dyn Error>>> + 'a {
+        let mut endpoint = format!(
+            "{}/{}/address/{}/transactions_v2/?key={}",
+            self.base_url, self.chain_id, addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+        self.items_at(endpoint)
+    }
+
+    /// Stream a page of the transactions in a block with their log events as the response
+    /// downloads, the most recent page if no page number is given
+    pub fn get_block_transactions_items<'a>(
+        &'a self,
+        block_height: &str,
+        page_number: Option<String>,
+    ) -> impl Stream<Item = Result<BlockTransactionWithLogEvents, Box<dyn Error>>> + 'a {
+        let endpoint = match page_number {
+            Some(page_number) => format!(
+                "{}/{}/block/{}/transactions_v3/page/{}/?key={}",
+                self.base_url, self.chain_id, block_height, page_number, self.api_key
+            ),
+            None => format!(
+                "{}/{}/block/{}/transactions_v3/?key={}",
+                self.base_url, self.chain_id, block_height, self.api_key
+            ),
+        };
+        self.items_at(endpoint)
+    }
+
+    /// Stream the log events of a contract address within a start and end block as the response
+    /// downloads
+    pub fn get_log_events_by_contract_items<'a>(
+        &'a self,
+        contract_addr: &str,
+        starting_block: &str,
+        ending_block: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> impl Stream<Item = Result<LogEventItem, Box<dyn Error>>> + 'a {
+        let mut endpoint = format!(
+            "{}/{}/events/address/{}/?starting-block={}&ending-block={}&key={}",
+            self.base_url, self.chain_id, contract_addr, starting_block, ending_block, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+        self.items_at(endpoint)
+    }
+
+    /// Stream all contract metadata as the response downloads, without the doubled up list the
+    /// API returns the items in
+    pub fn get_all_contract_metadata_items<'a>(
+        &'a self,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> impl Stream<Item = Result<ContractMetadataItem, Box<dyn Error>>> + 'a {
+        let mut endpoint = format!(
+            "{}/{}/tokens/tokenlists/all/?key={}",
+            self.base_url, self.chain_id, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+        self.items_at::<Vec<ContractMetadataItem>>(endpoint)
+            .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = r#"{"data": {"updated_at": "2022-05-20T10:38:30Z", "items": [
+        {"name": "a \"quoted\" ]} name", "items": [1, 2]},
+        {"name": "b", "nested": {"data": {"items": [3]}}}
+    ], "pagination": null}, "error": false, "error_message": null, "error_code": null}"#;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Item {
+        name: String,
+    }
+
+    /// Decode a body fed in chunks of `size` bytes
+    async fn decode<T: DeserializeOwned>(
+        body: &str,
+        size: usize,
+    ) -> Result<Vec<T>, Box<dyn Error>> {
+        let chunks: Vec<Result<&[u8], Box<dyn Error>>> =
+            body.as_bytes().chunks(size).map(Ok).collect();
+        decode_items(stream::iter(chunks)).try_collect().await
+    }
+
+    #[tokio::test]
+    async fn test_decode_items() {
+        // split at every size so chunks end inside strings, escapes and keys
+        for size in 1..BODY.len() {
+            let items: Vec<Item> = decode(BODY, size).await.unwrap();
+            assert_eq!(
+                vec![
+                    Item {
+                        name: "a \"quoted\" ]} name".to_string()
+                    },
+                    Item {
+                        name: "b".to_string()
+                    },
+                ],
+                items
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decode_items_errors() {
+        let flagged = r#"{"data": null, "error": true, "error_message": "Invalid address", "error_code": 400}"#;
+        let e = decode::<Item>(flagged, 7).await.unwrap_err();
+        assert_eq!("Invalid address", e.to_string());
+
+        let truncated = &BODY[..BODY.len() / 2];
+        assert!(decode::<Item>(truncated, 7).await.is_err());
+
+        let empty = r#"{"data": {"items": []}, "error": false}"#;
+        assert_eq!(0, decode::<Item>(empty, 7).await.unwrap().len());
+
+        let contract_metadata =
+            r#"{"data": {"items": [[{"name": "a"}, {"name": "b"}]]}, "error": false}"#;
+        let lists: Vec<Vec<Item>> = decode(contract_metadata, 7).await.unwrap();
+        assert_eq!(2, lists[0].len());
+    }
+}
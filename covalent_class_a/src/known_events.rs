@@ -0,0 +1,373 @@
+//! Typed decoders for well-known token and DEX events
+//!
+//! Turns a `LogEventItem` into a `KnownEvent` by matching topic0 and decoding the raw topics and
+//! data, so amounts are exact and nothing depends on Covalent's decoded names. Covers
+//! ERC20/KIP-7 `Transfer` and `Approval`, ERC721/KIP-17 `Transfer`, ERC1155 `TransferSingle` and
+//! `TransferBatch`, and Uniswap-V2-style `Swap`, `Mint`, `Burn` and `Sync`.
+//! ```
+//! use covalent_class_a::known_events::KnownEvent;
+//!
+//! fn print_transfer(log: &covalent_class_a::resources::LogEventItem) {
+//!     if let Some(KnownEvent::Erc20Transfer { from, to, value, .. }) = KnownEvent::from_log(log) {
+//!         println!("{} -> {}: {}", from, to, value);
+//!     }
+//! }
+//! ```
+use crate::abi::{parse_abi_events, AbiEvent};
+use crate::amount::U256;
+use crate::param::ParamValue;
+use crate::resources::LogEventItem;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum KnownEvent {
+    /// ERC20/KIP-7 `Transfer(address indexed from, address indexed to, uint256 value)`
+    Erc20Transfer {
+        token: String,
+        from: String,
+        to: String,
+        value: U256,
+    },
+    /// ERC20/KIP-7 `Approval(address indexed owner, address indexed spender, uint256 value)`
+    Erc20Approval {
+        token: String,
+        owner: String,
+        spender: String,
+        value: U256,
+    },
+    /// ERC721/KIP-17 `Transfer(address indexed from, address indexed to, uint256 indexed tokenId)`
+    Erc721Transfer {
+        token: String,
+        from: String,
+        to: String,
+        token_id: U256,
+    },
+    /// ERC1155 `TransferSingle(address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value)`
+    Erc1155TransferSingle {
+        token: String,
+        operator: String,
+        from: String,
+        to: String,
+        id: U256,
+        value: U256,
+    },
+    /// ERC1155 `TransferBatch(address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values)`
+    Erc1155TransferBatch {
+        token: String,
+        operator: String,
+        from: String,
+        to: String,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+    },
+    /// Uniswap V2 `Swap(address indexed sender, uint amount0In, uint amount1In, uint amount0Out, uint amount1Out, address indexed to)`
+    Swap {
+        pair: String,
+        sender: String,
+        amount0_in: U256,
+        amount1_in: U256,
+        amount0_out: U256,
+        amount1_out: U256,
+        to: String,
+    },
+    /// Uniswap V2 `Mint(address indexed sender, uint amount0, uint amount1)`
+    Mint {
+        pair: String,
+        sender: String,
+        amount0: U256,
+        amount1: U256,
+    },
+    /// Uniswap V2 `Burn(address indexed sender, uint amount0, uint amount1, address indexed to)`
+    Burn {
+        pair: String,
+        sender: String,
+        amount0: U256,
+        amount1: U256,
+        to: String,
+    },
+    /// Uniswap V2 `Sync(uint112 reserve0, uint112 reserve1)`
+    Sync {
+        pair: String,
+        reserve0: U256,
+        reserve1: U256,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EventKind {
+    Erc20Transfer,
+    Erc20Approval,
+    Erc721Transfer,
+    Erc1155TransferSingle,
+    Erc1155TransferBatch,
+    Swap,
+    Mint,
+    Burn,
+    Sync,
+}
+
+const KNOWN_EVENTS_ABI: &str = r#"[
+    {"type":"event","name":"Transfer","inputs":[
+        {"name":"from","type":"address","indexed":true},
+        {"name":"to","type":"address","indexed":true},
+        {"name":"value","type":"uint256","indexed":false}]},
+    {"type":"event","name":"Approval","inputs":[
+        {"name":"owner","type":"address","indexed":true},
+        {"name":"spender","type":"address","indexed":true},
+        {"name":"value","type":"uint256","indexed":false}]},
+    {"type":"event","name":"Transfer","inputs":[
+        {"name":"from","type":"address","indexed":true},
+        {"name":"to","type":"address","indexed":true},
+        {"name":"tokenId","type":"uint256","indexed":true}]},
+    {"type":"event","name":"TransferSingle","inputs":[
+        {"name":"operator","type":"address","indexed":true},
+        {"name":"from","type":"address","indexed":true},
+        {"name":"to","type":"address","indexed":true},
+        {"name":"id","type":"uint256","indexed":false},
+        {"name":"value","type":"uint256","indexed":false}]},
+    {"type":"event","name":"TransferBatch","inputs":[
+        {"name":"operator","type":"address","indexed":true},
+        {"name":"from","type":"address","indexed":true},
+        {"name":"to","type":"address","indexed":true},
+        {"name":"ids","type":"uint256[]","indexed":false},
+        {"name":"values","type":"uint256[]","indexed":false}]},
+    {"type":"event","name":"Swap","inputs":[
+        {"name":"sender","type":"address","indexed":true},
+        {"name":"amount0In","type":"uint256","indexed":false},
+        {"name":"amount1In","type":"uint256","indexed":false},
+        {"name":"amount0Out","type":"uint256","indexed":false},
+        {"name":"amount1Out","type":"uint256","indexed":false},
+        {"name":"to","type":"address","indexed":true}]},
+    {"type":"event","name":"Mint","inputs":[
+        {"name":"sender","type":"address","indexed":true},
+        {"name":"amount0","type":"uint256","indexed":false},
+        {"name":"amount1","type":"uint256","indexed":false}]},
+    {"type":"event","name":"Burn","inputs":[
+        {"name":"sender","type":"address","indexed":true},
+        {"name":"amount0","type":"uint256","indexed":false},
+        {"name":"amount1","type":"uint256","indexed":false},
+        {"name":"to","type":"address","indexed":true}]},
+    {"type":"event","name":"Sync","inputs":[
+        {"name":"reserve0","type":"uint112","indexed":false},
+        {"name":"reserve1","type":"uint112","indexed":false}]}
+]"#;
+
+lazy_static! {
+    /// The known events keyed by topic0
+    /// events sharing a topic0 are told apart by their number of topics
+    static ref KNOWN_EVENTS: HashMap<String, Vec<(AbiEvent, EventKind)>> = {
+        let kinds = [
+            EventKind::Erc20Transfer,
+            EventKind::Erc20Approval,
+            EventKind::Erc721Transfer,
+            EventKind::Erc1155TransferSingle,
+            EventKind::Erc1155TransferBatch,
+            EventKind::Swap,
+            EventKind::Mint,
+            EventKind::Burn,
+            EventKind::Sync,
+        ];
+        let events =
+            parse_abi_events(KNOWN_EVENTS_ABI).expect("Known events ABI should be valid");
+        let mut known_events: HashMap<String, Vec<(AbiEvent, EventKind)>> = HashMap::new();
+        for (event, kind) in events.into_iter().zip(kinds) {
+            known_events.entry(event.topic()).or_default().push((event, kind));
+        }
+        known_events
+    };
+}
+
+impl KnownEvent {
+    /// Decode a log event into a known event, `None` if topic0 is not one of the known events
+    pub fn from_log(log: &LogEventItem) -> Option<KnownEvent> {
+        let topics = log.raw_log_topics.as_deref()?;
+        let data = log.raw_log_data.as_deref().unwrap_or_default();
+        let candidates = KNOWN_EVENTS.get(&topics.first()?.to_lowercase())?;
+        candidates.iter().find_map(|(event, kind)| {
+            let decoded = event.decode(topics, data).ok()?;
+            let values: Vec<ParamValue> = decoded
+                .params?
+                .into_iter()
+                .filter_map(|p| p.value)
+                .collect();
+            kind.build(log.sender_address.clone(), &values)
+        })
+    }
+
+    /// The address of the contract which emitted the event
+    pub fn contract_address(&self) -> &str {
+        match self {
+            KnownEvent::Erc20Transfer { token, .. }
+            | KnownEvent::Erc20Approval { token, .. }
+            | KnownEvent::Erc721Transfer { token, .. }
+            | KnownEvent::Erc1155TransferSingle { token, .. }
+            | KnownEvent::Erc1155TransferBatch { token, .. } => token,
+            KnownEvent::Swap { pair, .. }
+            | KnownEvent::Mint { pair, .. }
+            | KnownEvent::Burn { pair, .. }
+            | KnownEvent::Sync { pair, .. } => pair,
+        }
+    }
+}
+
+fn address(values: &[ParamValue], i: usize) -> Option<String> {
+    values.get(i)?.as_address().map(str::to_string)
+}
+
+fn uint(values: &[ParamValue], i: usize) -> Option<U256> {
+    values.get(i)?.as_uint()
+}
+
+fn uints(values: &[ParamValue], i: usize) -> Option<Vec<U256>> {
+    values
+        .get(i)?
+        .as_array()?
+        .iter()
+        .map(ParamValue::as_uint)
+        .collect()
+}
+
+impl EventKind {
+    fn build(self, contract: String, v: &[ParamValue]) -> Option<KnownEvent> {
+        Some(match self {
+            EventKind::Erc20Transfer => KnownEvent::Erc20Transfer {
+                token: contract,
+                from: address(v, 0)?,
+                to: address(v, 1)?,
+                value: uint(v, 2)?,
+            },
+            EventKind::Erc20Approval => KnownEvent::Erc20Approval {
+                token: contract,
+                owner: address(v, 0)?,
+                spender: address(v, 1)?,
+                value: uint(v, 2)?,
+            },
+            EventKind::Erc721Transfer => KnownEvent::Erc721Transfer {
+                token: contract,
+                from: address(v, 0)?,
+                to: address(v, 1)?,
+                token_id: uint(v, 2)?,
+            },
+            EventKind::Erc1155TransferSingle => KnownEvent::Erc1155TransferSingle {
+                token: contract,
+                operator: address(v, 0)?,
+                from: address(v, 1)?,
+                to: address(v, 2)?,
+                id: uint(v, 3)?,
+                value: uint(v, 4)?,
+            },
+            EventKind::Erc1155TransferBatch => KnownEvent::Erc1155TransferBatch {
+                token: contract,
+                operator: address(v, 0)?,
+                from: address(v, 1)?,
+                to: address(v, 2)?,
+                ids: uints(v, 3)?,
+                values: uints(v, 4)?,
+            },
+            EventKind::Swap => KnownEvent::Swap {
+                pair: contract,
+                sender: address(v, 0)?,
+                amount0_in: uint(v, 1)?,
+                amount1_in: uint(v, 2)?,
+                amount0_out: uint(v, 3)?,
+                amount1_out: uint(v, 4)?,
+                to: address(v, 5)?,
+            },
+            EventKind::Mint => KnownEvent::Mint {
+                pair: contract,
+                sender: address(v, 0)?,
+                amount0: uint(v, 1)?,
+                amount1: uint(v, 2)?,
+            },
+            EventKind::Burn => KnownEvent::Burn {
+                pair: contract,
+                sender: address(v, 0)?,
+                amount0: uint(v, 1)?,
+                amount1: uint(v, 2)?,
+                to: address(v, 3)?,
+            },
+            EventKind::Sync => KnownEvent::Sync {
+                pair: contract,
+                reserve0: uint(v, 0)?,
+                reserve1: uint(v, 1)?,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::event_topic;
+
+    fn word(hex_value: &str) -> String {
+        format!("{:0>64}", hex_value)
+    }
+
+    fn log(topics: Vec<String>, data: String) -> LogEventItem {
+        let json = serde_json::json!({
+            "block_signed_at": "2022-05-18T00:00:01Z",
+            "block_height": 91321199,
+            "tx_offset": 0,
+            "log_offset": 0,
+            "tx_hash": "0x269fad968de5baf8d324b64d0a19df72ccfc762b33e1760729633f4946e0c863",
+            "raw_log_topics": topics,
+            "sender_contract_decimals": 18,
+            "sender_address": "0x5c74070fdea071359b86082bd9f9b3deaafbe32b",
+            "raw_log_data": data,
+            "decoded": null
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_transfer_by_topic_count() {
+        let from = format!("0x{}", word("f4024faad5fafd0755875e3161524c9c4e1a1111"));
+        let to = format!("0x{}", word("1"));
+        let topic0 = event_topic("Transfer(address,address,uint256)");
+
+        let erc20 = log(
+            vec![topic0.clone(), from.clone(), to.clone()],
+            format!("0x{}", word("a")),
+        );
+        assert_eq!(
+            Some(KnownEvent::Erc20Transfer {
+                token: "0x5c74070fdea071359b86082bd9f9b3deaafbe32b".to_string(),
+                from: "0xf4024faad5fafd0755875e3161524c9c4e1a1111".to_string(),
+                to: "0x0000000000000000000000000000000000000001".to_string(),
+                value: U256::new(10),
+            }),
+            KnownEvent::from_log(&erc20)
+        );
+
+        let erc721 = log(
+            vec![topic0, from, to, format!("0x{}", word("7"))],
+            "0x".to_string(),
+        );
+        assert!(matches!(
+            KnownEvent::from_log(&erc721),
+            Some(KnownEvent::Erc721Transfer { token_id, .. }) if token_id == U256::new(7)
+        ));
+    }
+
+    #[test]
+    fn test_sync() {
+        let sync = log(
+            vec![event_topic("Sync(uint112,uint112)")],
+            format!("0x{}{}", word("64"), word("c8")),
+        );
+        assert_eq!(
+            Some(KnownEvent::Sync {
+                pair: "0x5c74070fdea071359b86082bd9f9b3deaafbe32b".to_string(),
+                reserve0: U256::new(100),
+                reserve1: U256::new(200),
+            }),
+            KnownEvent::from_log(&sync)
+        );
+        assert_eq!(
+            None,
+            KnownEvent::from_log(&log(vec![word("0")], "0x".to_string()))
+        );
+    }
+}
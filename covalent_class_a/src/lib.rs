@@ -1,15 +1,29 @@
 use log::info;
 use reqwest::Response;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::env;
 use std::error::Error;
+use std::io::Cursor;
 
+pub mod abi;
+pub mod amount;
+#[cfg(feature = "chrono")]
+pub mod blocks;
+pub mod drift;
+pub mod endpoints;
+pub mod fast;
+pub mod holders;
+pub mod items;
+pub mod known_events;
+pub mod paginate;
+pub mod param;
+#[cfg(feature = "chrono")]
+pub mod prices;
+pub mod primer;
+pub mod ranged_logs;
 pub mod resources;
-
-async fn make_request(url: &str) -> Result<Response, Box<dyn Error>> {
-    info!("Sending API request to: {}", url);
-    let resp = reqwest::get(url).await?;
-    Ok(resp)
-}
+pub mod topics;
 
 /// Get the Covalent API key from environment variables
 fn get_env_api_key() -> Result<String, Box<dyn Error>> {
@@ -33,20 +47,29 @@ fn add_pagination_params(
     page_size: Option<String>,
     page_number: Option<String>,
 ) -> String {
-    if page_size.is_some() {
-        endpoint = format!("{}&page-size={}", endpoint, page_size.unwrap())
+    if let Some(page_size) = page_size {
+        endpoint = format!("{}&page-size={}", endpoint, page_size)
     }
-    if page_number.is_some() {
-        endpoint = format!("{}&page-number={}", endpoint, page_number.unwrap())
+    if let Some(page_number) = page_number {
+        endpoint = format!("{}&page-number={}", endpoint, page_number)
     }
     endpoint
 }
 
+/// Check for a 0x-prefixed 20 byte hex address
+fn is_hex_address(addr: &str) -> bool {
+    addr.len() == 42 && addr.starts_with("0x") && addr[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
 #[derive(Clone)]
 pub struct CovalentClient {
     pub base_url: String,
     pub chain_id: String,
     pub api_key: String,
+    // Primer query parameters added to every request
+    primer: Option<primer::Primer>,
+    // checks each response for drift from the struct it is decoded into
+    drift_check: Option<drift::DriftCheck>,
 }
 
 impl CovalentClient {
@@ -65,6 +88,8 @@ impl CovalentClient {
             base_url: "https://api.covalenthq.com/v1".to_string(),
             chain_id: chain_id.to_string(),
             api_key: api_key.to_string(),
+            primer: None,
+            drift_check: None,
         })
     }
 
@@ -84,9 +109,251 @@ impl CovalentClient {
             base_url: "https://api.covalenthq.com/v1".to_string(),
             chain_id: chain_id.to_string(),
             api_key: get_env_api_key()?,
+            primer: None,
+            drift_check: None,
         })
     }
 
+    /// Create a copy of this client which adds Primer query parameters to every request, to
+    /// filter and aggregate items on the server
+    /// ```no_run
+    /// use covalent_class_a::primer::{Match, Primer};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let klaytn_client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+    ///     let successful = klaytn_client.with_primer(Primer::new().filter(Match::eq("successful", true)));
+    ///     let transactions = successful.get_transactions_for_address("0xf4024faad5fafd0755875e3161524c9c4e1a1111", None, None).await.unwrap();
+    /// }
+    /// ```
+    pub fn with_primer(&self, primer: primer::Primer) -> CovalentClient {
+        CovalentClient {
+            primer: Some(primer),
+            ..self.clone()
+        }
+    }
+
+    /// Create a copy of this client which checks every response for fields that have drifted
+    /// from the resource structs, logging or collecting the diagnostics
+    pub fn with_drift_check(&self, mode: drift::DriftMode) -> CovalentClient {
+        CovalentClient {
+            drift_check: Some(drift::DriftCheck::new(mode)),
+            ..self.clone()
+        }
+    }
+
+    /// Take the drift reports collected by a client created with `DriftMode::Collect`
+    pub fn take_drift_reports(&self) -> Vec<drift::DriftReport> {
+        match &self.drift_check {
+            Some(drift_check) => drift_check.take_reports(),
+            None => vec![],
+        }
+    }
+
+    /// Decode a response into a resource, checking it for drift first if a drift check is set
+    async fn decode<T: DeserializeOwned + Serialize + drift::Modelled>(
+        &self,
+        endpoint: &str,
+        resp: Response,
+    ) -> Result<T, Box<dyn Error>> {
+        let drift_check = match &self.drift_check {
+            Some(drift_check) => drift_check,
+            None => {
+                let bytes = resp.bytes().await?;
+                // simd-json parses in place so it is given its own copy of the body
+                #[cfg(feature = "simd-json")]
+                return fast::from_slice(&mut bytes.to_vec());
+                #[cfg(not(feature = "simd-json"))]
+                return Ok(serde_json::from_slice(&bytes)?);
+            }
+        };
+        let raw: serde_json::Value = resp.json().await?;
+        drift_check.check_response::<T>(endpoint, &raw);
+        Ok(serde_json::from_value(raw)?)
+    }
+
+    async fn make_request(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let url = match &self.primer {
+            Some(primer) => format!("{}{}", url, primer.query_params()),
+            None => url.to_string(),
+        };
+        info!("Sending API request to: {}", url);
+        let resp = reqwest::get(&url).await?;
+        Ok(resp)
+    }
+
+    /// The URL of an endpoint given by its path relative to the chain of the client e.g.
+    /// "address/0xf4024faad5fafd0755875e3161524c9c4e1a1111/balances_v2/"
+    fn path_endpoint(
+        &self,
+        path: &str,
+        format: Option<&str>,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> String {
+        let mut endpoint = format!(
+            "{}/{}/{}?",
+            self.base_url,
+            self.chain_id,
+            path.trim_start_matches('/')
+        );
+        if let Some(format) = format {
+            endpoint = format!("{}format={}&", endpoint, format)
+        }
+        endpoint = format!("{}key={}", endpoint, self.api_key);
+        for (name, value) in params {
+            endpoint = format!("{}&{}={}", endpoint, name, value)
+        }
+        add_pagination_params(endpoint, page_size, page_number)
+    }
+
+    /// Get any endpoint as untyped JSON, for reading fields of a response before they are
+    /// modelled in `resources`. Extra query parameters are given as name and value pairs
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let klaytn_client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+    ///     let balances = klaytn_client
+    ///         .get_raw("address/0xf4024faad5fafd0755875e3161524c9c4e1a1111/balances_v2/", &[("nft", "true")], None, None)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{}", balances["data"]["items"][0]);
+    /// }
+    /// ```
+    pub async fn get_raw(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<serde_json::Value, Box<dyn Error>> {
+        let endpoint = self.path_endpoint(path, None, params, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let raw: serde_json::Value = resp.json().await?;
+        Ok(raw)
+    }
+
+    /// Get any endpoint as the undecoded JSON body, for decoding into the borrowed types of
+    /// `fast` with `fast::from_slice`. Extra query parameters are given as name and value pairs
+    pub async fn get_raw_bytes(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let endpoint = self.path_endpoint(path, None, params, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// Get any endpoint grouped by the Primer `group` of the client, whose items are the groups
+    /// rather than the items of the endpoint. Extra query parameters are given as name and value
+    /// pairs
+    /// ```no_run
+    /// use covalent_class_a::primer::{Aggregate, Group, Primer};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let klaytn_client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+    ///     let by_recipient = klaytn_client.with_primer(
+    ///         Primer::new().group(Group::by("to_address").aggregate("count", Aggregate::Count)),
+    ///     );
+    ///     let grouped = by_recipient
+    ///         .get_grouped("address/0xf4024faad5fafd0755875e3161524c9c4e1a1111/transactions_v2/", &[], None, None)
+    ///         .await
+    ///         .unwrap();
+    ///     for group in grouped.data.items {
+    ///         println!("{:?} {:?}", group.get("_id"), group.get("count"));
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_grouped(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<primer::GroupedData, Box<dyn Error>> {
+        if self
+            .primer
+            .as_ref()
+            .and_then(|primer| primer.group.as_ref())
+            .is_none()
+        {
+            return Err("A grouped request needs a client with a Primer group".into());
+        }
+        let endpoint = self.path_endpoint(path, None, params, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: primer::GroupedData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get any endpoint with `format=csv`, returning the response undecoded so the CSV body can
+    /// be streamed with `chunk()`. Extra query parameters are given as name and value pairs
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let klaytn_client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+    ///     let mut resp = klaytn_client
+    ///         .get_csv_response("address/0xf4024faad5fafd0755875e3161524c9c4e1a1111/balances_v2/", &[], None, None)
+    ///         .await
+    ///         .unwrap();
+    ///     while let Some(chunk) = resp.chunk().await.unwrap() {
+    ///         print!("{}", String::from_utf8_lossy(&chunk));
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_csv_response(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<Response, Box<dyn Error>> {
+        let endpoint = self.path_endpoint(path, Some("csv"), params, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            return Err(
+                format!("CSV request failed with {}: {}", status, resp.text().await?).into(),
+            );
+        }
+        Ok(resp)
+    }
+
+    /// Get the CSV body of any endpoint with `format=csv` as bytes
+    pub async fn get_csv(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let resp = self
+            .get_csv_response(path, params, page_size, page_number)
+            .await?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// Get any endpoint with `format=csv` as a `csv::Reader` over its rows, the first row is read
+    /// as the header
+    pub async fn get_csv_reader(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<csv::Reader<Cursor<Vec<u8>>>, Box<dyn Error>> {
+        let csv = self.get_csv(path, params, page_size, page_number).await?;
+        Ok(csv::Reader::from_reader(Cursor::new(csv)))
+    }
+
     /// Get token balance information for an address
     pub async fn get_token_balances(
         &self,
@@ -100,8 +367,53 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::BalancesData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::BalancesData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get token balance information for an address including NFTs with their metadata in `nft_data`
+    pub async fn get_token_balances_with_nfts(
+        &self,
+        addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::BalancesData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/address/{}/balances_v2/?nft=true&no-nft-fetch=false&key={}",
+            self.base_url, self.chain_id, addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::BalancesData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get token balances of an address as they were at a block height or on a date in
+    /// YYYY-MM-DD format, the latest balances are returned if neither is given
+    pub async fn get_historical_token_balances(
+        &self,
+        addr: &str,
+        block_height: Option<&str>,
+        date: Option<&str>,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::HistoricalBalancesData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/address/{}/historical_balances/?key={}",
+            self.base_url, self.chain_id, addr, self.api_key
+        );
+        if let Some(block_height) = block_height {
+            endpoint = format!("{}&block-height={}", endpoint, block_height)
+        }
+        if let Some(date) = date {
+            endpoint = format!("{}&date={}", endpoint, date)
+        }
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::HistoricalBalancesData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -118,8 +430,8 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::HistoricalPortfolioData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::HistoricalPortfolioData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -137,15 +449,17 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::TokenTransfersData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::TokenTransfersData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
-    /// Get token holders at a block height for an address
+    /// Get token holders for an address at a block height, or the latest holders if no block
+    /// height is given
     pub async fn get_token_holders_any_bh(
         &self,
         addr: &str,
+        block_height: Option<&str>,
         page_size: Option<String>,
         page_number: Option<String>,
     ) -> Result<resources::TokenHoldersData, Box<dyn Error>> {
@@ -153,10 +467,13 @@ impl CovalentClient {
             "{}/{}/tokens/{}/token_holders/?key={}",
             self.base_url, self.chain_id, addr, self.api_key
         );
+        if let Some(block_height) = block_height {
+            endpoint = format!("{}&block-height={}", endpoint, block_height)
+        }
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::TokenHoldersData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::TokenHoldersData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -175,8 +492,8 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::ChangesInTokenHoldersData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::ChangesInTokenHoldersData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -193,8 +510,70 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::TransactionsData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::TransactionsData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get a page of transactions for an address from the v3 endpoint, which pages by URL path
+    /// and links to the previous and next pages, the most recent page is returned if no page
+    /// number is given
+    pub async fn get_transactions_for_address_v3(
+        &self,
+        addr: &str,
+        page_number: Option<String>,
+    ) -> Result<resources::TransactionsV3Data, Box<dyn Error>> {
+        let endpoint = match page_number {
+            Some(page_number) => format!(
+                "{}/{}/address/{}/transactions_v3/page/{}/?key={}",
+                self.base_url, self.chain_id, addr, page_number, self.api_key
+            ),
+            None => format!(
+                "{}/{}/address/{}/transactions_v3/?key={}",
+                self.base_url, self.chain_id, addr, self.api_key
+            ),
+        };
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::TransactionsV3Data = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get the page behind a `links.prev` or `links.next` URL returned by a v3 endpoint. The link
+    /// must be under the client's `base_url`, as the API key is appended to it
+    pub async fn get_page_link<T: DeserializeOwned + Serialize + drift::Modelled>(
+        &self,
+        link: &str,
+    ) -> Result<T, Box<dyn Error>> {
+        let under_base_url = link
+            .strip_prefix(&self.base_url)
+            .is_some_and(|path| path.starts_with('/'));
+        if !under_base_url {
+            return Err(format!("Page link {} is not under {}", link, self.base_url).into());
+        }
+        let separator = if link.contains('?') { '&' } else { '?' };
+        let endpoint = format!("{}{}key={}", link, separator, self.api_key);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: T = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get the transaction count and the earliest and latest transactions of an address
+    pub async fn get_transaction_summary(
+        &self,
+        addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::TransactionSummaryData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/address/{}/transactions_summary/?key={}",
+            self.base_url, self.chain_id, addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::TransactionSummaryData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -211,8 +590,8 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::TransactionData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::TransactionData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -229,8 +608,32 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::BlockData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::BlockData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get a page of the transactions in a block with their log events, which pages by URL path
+    /// and links to the previous and next pages, the most recent page is returned if no page
+    /// number is given
+    pub async fn get_block_transactions(
+        &self,
+        block_height: &str,
+        page_number: Option<String>,
+    ) -> Result<resources::BlockTransactionsData, Box<dyn Error>> {
+        let endpoint = match page_number {
+            Some(page_number) => format!(
+                "{}/{}/block/{}/transactions_v3/page/{}/?key={}",
+                self.base_url, self.chain_id, block_height, page_number, self.api_key
+            ),
+            None => format!(
+                "{}/{}/block/{}/transactions_v3/?key={}",
+                self.base_url, self.chain_id, block_height, self.api_key
+            ),
+        };
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::BlockTransactionsData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -248,8 +651,8 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::BlockData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::BlockData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -263,13 +666,13 @@ impl CovalentClient {
         page_number: Option<String>,
     ) -> Result<resources::LogEventsGenericData, Box<dyn Error>> {
         let mut endpoint = format!(
-            "{}/{}/events/address/{}/token_holders_changes/?starting-block={}&ending-block={}&key={}",
+            "{}/{}/events/address/{}/?starting-block={}&ending-block={}&key={}",
             self.base_url, self.chain_id, contract_addr, starting_block, ending_block, self.api_key
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::LogEventsGenericData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::LogEventsGenericData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -283,20 +686,54 @@ impl CovalentClient {
         page_size: Option<String>,
         page_number: Option<String>,
     ) -> Result<resources::LogEventsGenericData, Box<dyn Error>> {
+        let mut filter = topics::TopicFilter::new().sender(sender_addr);
+        for topic in topic_hash.split(',') {
+            filter = filter.topic(topic.trim());
+        }
+        self.get_log_events_by_topic_filter(
+            &filter,
+            starting_block,
+            ending_block,
+            page_size,
+            page_number,
+        )
+        .await
+    }
+
+    /// Get log events matching a topic filter of primary and secondary topics and a sender
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let eth_client = covalent_class_a::CovalentClient::new_env_api_key("1").unwrap();
+    ///     let filter = covalent_class_a::topics::TopicFilter::new()
+    ///         .event_signature("Transfer(address,address,uint256)")
+    ///         .sender("0x6b175474e89094c44da98b954eedeac495271d0f");
+    ///     let log_events = eth_client.get_log_events_by_topic_filter(&filter, "12500000", "12500100", None, None).await.unwrap();
+    /// }
+    /// ```
+    pub async fn get_log_events_by_topic_filter(
+        &self,
+        filter: &topics::TopicFilter,
+        starting_block: &str,
+        ending_block: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::LogEventsGenericData, Box<dyn Error>> {
+        filter.validate()?;
         let mut endpoint = format!(
-            "{}/{}/events/topics/{}/?starting-block={}&ending-block={}&sender-address={}&key={}",
+            "{}/{}/events/topics/{}/?starting-block={}&ending-block={}{}&key={}",
             self.base_url,
             self.chain_id,
-            topic_hash,
+            filter.path_topics(),
             starting_block,
             ending_block,
-            sender_addr,
+            filter.query_params(),
             self.api_key
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::LogEventsGenericData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::LogEventsGenericData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -312,8 +749,271 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::ContractMetadataData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::ContractMetadataData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get NFT token IDs for an NFT contract address
+    pub async fn get_nft_token_ids(
+        &self,
+        contract_addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::NftTokenIdsData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/tokens/{}/nft_token_ids/?key={}",
+            self.base_url, self.chain_id, contract_addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::NftTokenIdsData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get NFT transactions for a token ID of an NFT contract address
+    pub async fn get_nft_transactions(
+        &self,
+        contract_addr: &str,
+        token_id: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::NftTransactionsData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/tokens/{}/nft_transactions/{}/?key={}",
+            self.base_url, self.chain_id, contract_addr, token_id, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::NftTransactionsData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get NFT external metadata for a token ID of an NFT contract address
+    pub async fn get_nft_external_metadata(
+        &self,
+        contract_addr: &str,
+        token_id: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::NftMetadataData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/tokens/{}/nft_metadata/{}/?key={}",
+            self.base_url, self.chain_id, contract_addr, token_id, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::NftMetadataData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get historical prices for comma-separated contract addresses, optionally between two dates
+    /// in YYYY-MM-DD format
+    pub async fn get_historical_token_prices(
+        &self,
+        contract_addrs: &str,
+        quote_currency: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::HistoricalPricesData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/pricing/historical_by_addresses_v2/{}/{}/{}/?key={}",
+            self.base_url, self.chain_id, quote_currency, contract_addrs, self.api_key
+        );
+        if let Some(from) = from {
+            endpoint = format!("{}&from={}", endpoint, from)
+        }
+        if let Some(to) = to {
+            endpoint = format!("{}&to={}", endpoint, to)
+        }
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::HistoricalPricesData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get spot prices for comma-separated tickers such as "KLAY,ETH"
+    pub async fn get_spot_prices(
+        &self,
+        tickers: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::SpotPricesData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/pricing/tickers/?tickers={}&key={}",
+            self.base_url, tickers, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::SpotPricesData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get all XY=K pools of a DEX such as "uniswap_v2"
+    pub async fn get_xyk_pools(
+        &self,
+        dex_name: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::XykPoolsData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/xy=k/{}/pools/?key={}",
+            self.base_url, self.chain_id, dex_name, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::XykPoolsData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get an XY=K pool of a DEX by its pool address
+    pub async fn get_xyk_pool_by_address(
+        &self,
+        dex_name: &str,
+        pool_addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::XykPoolsData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/xy=k/{}/pools/address/{}/?key={}",
+            self.base_url, self.chain_id, dex_name, pool_addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::XykPoolsData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get the liquidity pool token balances of an address on a DEX
+    pub async fn get_xyk_address_exchange_balances(
+        &self,
+        dex_name: &str,
+        addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::XykAddressBalancesData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/xy=k/{}/address/{}/balances/?key={}",
+            self.base_url, self.chain_id, dex_name, addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::XykAddressBalancesData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get the swap and liquidity transactions of an XY=K pool of a DEX
+    pub async fn get_xyk_pool_transactions(
+        &self,
+        dex_name: &str,
+        pool_addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::XykTransactionsData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/xy=k/{}/pools/address/{}/transactions/?key={}",
+            self.base_url, self.chain_id, dex_name, pool_addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::XykTransactionsData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get the 7 and 30 day volume and liquidity charts of a DEX
+    pub async fn get_xyk_ecosystem_chart(
+        &self,
+        dex_name: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::XykEcosystemData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/xy=k/{}/ecosystem/?key={}",
+            self.base_url, self.chain_id, dex_name, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::XykEcosystemData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get gas prices for an event type such as "erc20", "nativetokens" or "uniswapv3"
+    pub async fn get_gas_prices(
+        &self,
+        event_type: &str,
+        quote_currency: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::GasPricesData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/event/{}/gas_prices/?quote-currency={}&key={}",
+            self.base_url, self.chain_id, event_type, quote_currency, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::GasPricesData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Get the address a name-service domain such as "demo.eth" resolves to
+    pub async fn get_resolved_address(
+        &self,
+        name: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::ResolvedAddressData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/{}/address/{}/resolve_address/?key={}",
+            self.base_url, self.chain_id, name, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::ResolvedAddressData = self.decode(&endpoint, resp).await?;
+        Ok(resource)
+    }
+
+    /// Resolve a name-service domain to an address, addresses are returned unchanged
+    pub async fn resolve_name(&self, name_or_addr: &str) -> Result<String, Box<dyn Error>> {
+        if is_hex_address(name_or_addr) {
+            return Ok(name_or_addr.to_string());
+        }
+        let resolved = self.get_resolved_address(name_or_addr, None, None).await?;
+        resolved.error.check()?;
+        match resolved.data.items.into_iter().next() {
+            Some(item) => Ok(item.address),
+            None => Err(format!("Name {} does not resolve to an address", name_or_addr).into()),
+        }
+    }
+
+    /// Get the chains an address has activity on, this is not bound to the chain_id of the client
+    pub async fn get_address_activity(
+        &self,
+        addr: &str,
+        page_size: Option<String>,
+        page_number: Option<String>,
+    ) -> Result<resources::AddressActivityData, Box<dyn Error>> {
+        let mut endpoint = format!(
+            "{}/address/{}/activity/?key={}",
+            self.base_url, addr, self.api_key
+        );
+        endpoint = add_pagination_params(endpoint, page_size, page_number);
+
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::AddressActivityData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -330,8 +1030,8 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::AllChainData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::AllChainData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 
@@ -348,8 +1048,8 @@ impl CovalentClient {
         );
         endpoint = add_pagination_params(endpoint, page_size, page_number);
 
-        let resp = make_request(&endpoint).await?;
-        let resource: resources::AllChainStatusesData = resp.json().await?;
+        let resp = self.make_request(&endpoint).await?;
+        let resource: resources::AllChainStatusesData = self.decode(&endpoint, resp).await?;
         Ok(resource)
     }
 }
@@ -369,12 +1069,89 @@ mod tests {
         static ref KLAYTN_ENDING_BLOCK: String = "91321208".to_string();
         static ref SAMPLE_START_DATE: String = "2022-05-18".to_string();
         static ref SAMPLE_END_DATE: String = "2022-05-20".to_string();
+        static ref SAMPLE_NFT_CONTRACT: String =
+            "0xe4605d46fd0b3f8329d936a8b258d69276cba264".to_string();
+        static ref SAMPLE_NFT_TOKEN_ID: String = "123".to_string();
+        static ref SAMPLE_DEX_NAME: String = "uniswap_v2".to_string();
+        static ref SAMPLE_DEX_POOL: String =
+            "0x21b8065d10f73ee2e260e5b47d3344d3ced7596e".to_string();
+        static ref SAMPLE_DEX_ADDR: String =
+            "0x4121dd930b15742b6d2e89b41284a79320bb8503".to_string();
     }
 
     fn setup_klaytn_client() -> CovalentClient {
         CovalentClient::new_env_api_key("8217").expect("Failed to create Klaytn Covalent client")
     }
 
+    #[test]
+    fn test_path_endpoint() {
+        let client = CovalentClient::new("8217", "ckey_test").unwrap();
+        assert_eq!(
+            "https://api.covalenthq.com/v1/8217/address/0xabc/transfers_v2/?format=csv&key=ckey_test&contract-address=0xdef&page-size=10",
+            client.path_endpoint(
+                "/address/0xabc/transfers_v2/",
+                Some("csv"),
+                &[("contract-address", "0xdef")],
+                Some("10".to_string()),
+                None
+            )
+        );
+        assert_eq!(
+            "https://api.covalenthq.com/v1/8217/block_v2/latest/?key=ckey_test",
+            client.path_endpoint("block_v2/latest/", None, &[], None, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_page_link_foreign_host() {
+        let client = CovalentClient::new("8217", "ckey_test").unwrap();
+        for link in [
+            "https://example.com/v1/8217/address/0xabc/transactions_v3/page/1/",
+            "https://api.covalenthq.com/v1.example.com/8217/address/0xabc/transactions_v3/page/1/",
+        ] {
+            let err = client
+                .get_page_link::<resources::TransactionsV3Data>(link)
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("is not under"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_raw() {
+        let client = setup_klaytn_client();
+        let raw = client
+            .get_raw(
+                &format!("address/{}/balances_v2/", KLAYTN_ADDR.as_str()),
+                &[],
+                None,
+                None,
+            )
+            .await
+            .expect("Should receive valid raw JSON balances");
+        assert_eq!(Some(false), raw["error"].as_bool());
+        assert!(raw["data"]["items"].is_array());
+    }
+
+    #[tokio::test]
+    async fn test_get_csv_reader() {
+        let client = setup_klaytn_client();
+        let mut reader = client
+            .get_csv_reader(
+                &format!("address/{}/balances_v2/", KLAYTN_ADDR.as_str()),
+                &[],
+                None,
+                None,
+            )
+            .await
+            .expect("Should receive valid CSV balances");
+        assert!(reader
+            .headers()
+            .expect("Should have a CSV header")
+            .iter()
+            .any(|header| header == "contract_address"));
+    }
+
     #[tokio::test]
     async fn test_get_token_balances() {
         let client = setup_klaytn_client();
@@ -385,6 +1162,48 @@ mod tests {
         assert_eq!(KLAYTN_ADDR.as_str(), balance.data.address);
     }
 
+    #[tokio::test]
+    async fn test_get_token_balances_with_nfts() {
+        let client = setup_klaytn_client();
+        let balance = client
+            .get_token_balances_with_nfts(&KLAYTN_ADDR, None, None)
+            .await
+            .expect("Should receive valid balance with NFTs");
+        assert_eq!(KLAYTN_ADDR.as_str(), balance.data.address);
+    }
+
+    #[tokio::test]
+    async fn test_get_historical_token_balances() {
+        let client = setup_klaytn_client();
+        let balances = client
+            .get_historical_token_balances(
+                &KLAYTN_ADDR,
+                Some(KLAYTN_ENDING_BLOCK.as_str()),
+                None,
+                None,
+                None,
+            )
+            .await
+            .expect("Should receive valid historical balances");
+        assert!(!balances.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_historical_token_balances_on_date() {
+        let client = setup_klaytn_client();
+        let balances = client
+            .get_historical_token_balances(
+                &KLAYTN_ADDR,
+                None,
+                Some(SAMPLE_END_DATE.as_str()),
+                None,
+                None,
+            )
+            .await
+            .expect("Should receive valid historical balances");
+        assert!(!balances.error.error)
+    }
+
     #[tokio::test]
     async fn test_get_historical_portfolio_value() {
         let client = setup_klaytn_client();
@@ -413,7 +1232,22 @@ mod tests {
     async fn test_get_token_holders_any_bh() {
         let client = setup_klaytn_client();
         let holders = client
-            .get_token_holders_any_bh(&KLAYTN_ADDR, None, None)
+            .get_token_holders_any_bh(&KLAYTN_ADDR, None, None, None)
+            .await
+            .expect("Should receive valid token holder");
+        assert!(!holders.error.error);
+    }
+
+    #[tokio::test]
+    async fn test_get_token_holders_at_block_height() {
+        let client = setup_klaytn_client();
+        let holders = client
+            .get_token_holders_any_bh(
+                &KLAYTN_CONTRACT_DAI,
+                Some(KLAYTN_ENDING_BLOCK.as_str()),
+                Some("10".to_string()),
+                None,
+            )
             .await
             .expect("Should receive valid token holder");
         assert!(!holders.error.error);
@@ -445,6 +1279,33 @@ mod tests {
         assert_eq!(KLAYTN_ADDR.as_str(), transactions.data.address);
     }
 
+    #[tokio::test]
+    async fn test_get_transactions_for_address_v3() {
+        let client = setup_klaytn_client();
+        let transactions = client
+            .get_transactions_for_address_v3(&KLAYTN_ADDR, Some("0".to_string()))
+            .await
+            .expect("Should receive valid v3 transactions");
+        assert!(!transactions.error.error);
+        if let Some(next) = transactions.data.links.next {
+            let next: resources::TransactionsV3Data = client
+                .get_page_link(&next)
+                .await
+                .expect("Should receive valid v3 transactions from the next link");
+            assert!(!next.error.error);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_summary() {
+        let client = setup_klaytn_client();
+        let summary = client
+            .get_transaction_summary(&KLAYTN_ADDR, None, None)
+            .await
+            .expect("Should receive valid transaction summary");
+        assert!(!summary.error.error)
+    }
+
     #[tokio::test]
     async fn test_get_transaction() {
         let client = setup_klaytn_client();
@@ -465,6 +1326,16 @@ mod tests {
         assert!(!block.error.error)
     }
 
+    #[tokio::test]
+    async fn test_get_block_transactions() {
+        let client = setup_klaytn_client();
+        let transactions = client
+            .get_block_transactions(&KLAYTN_ENDING_BLOCK, Some("0".to_string()))
+            .await
+            .expect("Should receive valid block transactions");
+        assert!(!transactions.error.error)
+    }
+
     #[tokio::test]
     async fn test_get_block_heights() {
         let client = setup_klaytn_client();
@@ -490,7 +1361,7 @@ mod tests {
             )
             .await
             .expect("Should receive valid log events");
-        assert!(log_events.error.error)
+        assert!(!log_events.error.error)
     }
 
     #[tokio::test]
@@ -524,6 +1395,170 @@ mod tests {
         assert!(!metadata.error.error)
     }
 
+    #[tokio::test]
+    async fn test_get_nft_token_ids() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let token_ids = client
+            .get_nft_token_ids(&SAMPLE_NFT_CONTRACT, Some("10".to_string()), None)
+            .await
+            .expect("Should receive valid NFT token IDs");
+        assert!(!token_ids.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_nft_transactions() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let transactions = client
+            .get_nft_transactions(&SAMPLE_NFT_CONTRACT, &SAMPLE_NFT_TOKEN_ID, None, None)
+            .await
+            .expect("Should receive valid NFT transactions");
+        assert!(!transactions.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_nft_external_metadata() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let metadata = client
+            .get_nft_external_metadata(&SAMPLE_NFT_CONTRACT, &SAMPLE_NFT_TOKEN_ID, None, None)
+            .await
+            .expect("Should receive valid NFT metadata");
+        assert!(!metadata.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_historical_token_prices() {
+        let client = setup_klaytn_client();
+        let prices = client
+            .get_historical_token_prices(
+                &KLAYTN_CONTRACT_DAI,
+                "USD",
+                Some(SAMPLE_START_DATE.as_str()),
+                Some(SAMPLE_END_DATE.as_str()),
+                None,
+                None,
+            )
+            .await
+            .expect("Should receive valid historical prices");
+        assert!(!prices.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_spot_prices() {
+        let client = setup_klaytn_client();
+        let prices = client
+            .get_spot_prices("KLAY,ETH", None, None)
+            .await
+            .expect("Should receive valid spot prices");
+        assert!(!prices.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_xyk_pools() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let pools = client
+            .get_xyk_pools(&SAMPLE_DEX_NAME, Some("10".to_string()), None)
+            .await
+            .expect("Should receive valid XY=K pools");
+        assert!(!pools.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_xyk_pool_by_address() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let pool = client
+            .get_xyk_pool_by_address(&SAMPLE_DEX_NAME, &SAMPLE_DEX_POOL, None, None)
+            .await
+            .expect("Should receive valid XY=K pool");
+        assert!(!pool.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_xyk_address_exchange_balances() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let balances = client
+            .get_xyk_address_exchange_balances(&SAMPLE_DEX_NAME, &SAMPLE_DEX_ADDR, None, None)
+            .await
+            .expect("Should receive valid XY=K address balances");
+        assert!(!balances.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_xyk_pool_transactions() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let transactions = client
+            .get_xyk_pool_transactions(
+                &SAMPLE_DEX_NAME,
+                &SAMPLE_DEX_POOL,
+                Some("10".to_string()),
+                None,
+            )
+            .await
+            .expect("Should receive valid XY=K pool transactions");
+        assert!(!transactions.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_xyk_ecosystem_chart() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let ecosystem = client
+            .get_xyk_ecosystem_chart(&SAMPLE_DEX_NAME, None, None)
+            .await
+            .expect("Should receive valid XY=K ecosystem chart");
+        assert!(!ecosystem.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_get_gas_prices() {
+        let client = setup_klaytn_client();
+        let gas_prices = client
+            .get_gas_prices("erc20", "USD", None, None)
+            .await
+            .expect("Should receive valid gas prices");
+        assert!(!gas_prices.error.error)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_name() {
+        let mut client = setup_klaytn_client();
+        // using the example from the covalent docs for this on the ethereum mainnet chain_id
+        client.chain_id = "1".to_string();
+        let addr = client
+            .resolve_name("demo.eth")
+            .await
+            .expect("Should resolve the name to an address");
+        assert!(is_hex_address(&addr));
+        assert_eq!(
+            KLAYTN_ADDR.as_str(),
+            client.resolve_name(&KLAYTN_ADDR).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_address_activity() {
+        let client = setup_klaytn_client();
+        let activity = client
+            .get_address_activity(&KLAYTN_ADDR, None, None)
+            .await
+            .expect("Should receive valid address activity");
+        assert!(!activity.error.error)
+    }
+
     #[tokio::test]
     async fn test_get_all_chains() {
         let client = setup_klaytn_client();
@@ -0,0 +1,350 @@
+//! Walking every page of a paginated endpoint
+//!
+//! Most endpoints page by number with `page-number` and report `has_more`, while the newer v3
+//! endpoints page by URL path and link to the next page in `links.next`. Responses of both kinds
+//! implement `Paginated`, and `paginate` turns a function fetching one page into a stream of the
+//! items of every page.
+//! ```no_run
+//! use futures::TryStreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+//!     let transactions: Vec<_> = client
+//!         .stream_transactions_v3("0xf4024faad5fafd0755875e3161524c9c4e1a1111")
+//!         .try_collect()
+//!         .await
+//!         .unwrap();
+//! }
+//! ```
+use crate::resources::{
+    ApiError, ApiPagination, BalancesData, BlockTransactionWithContractTransfers,
+    BlockTransactionWithLogEvents, BlockTransactionsData, ChangesInTokenHoldersData,
+    ChangesInTokenHoldersItem, LogEventItem, LogEventsGenericData, TokenHolderItem,
+    TokenHoldersData, TokenTransfersData, TransactionsData, TransactionsV3Data, WalletBalanceItem,
+};
+use crate::CovalentClient;
+use futures::stream::{self, Stream, TryStreamExt};
+use std::error::Error;
+use std::future::Future;
+
+/// Where a page is fetched from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageCursor {
+    /// A page number, starting from 0
+    Number(u64),
+    /// A `links.next` or `links.prev` URL
+    Link(String),
+}
+
+/// A response holding one page of items
+pub trait Paginated {
+    type Item;
+
+    /// The error flagged in the response
+    fn error(&self) -> &ApiError;
+
+    /// The cursor of the page after this one, given the cursor this page was fetched with
+    fn next_cursor(&self, current: &PageCursor) -> Option<PageCursor>;
+
+    /// Take the items of this page
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// The next page number after the current one if the pagination reports more pages
+fn next_page_number(
+    pagination: &Option<ApiPagination>,
+    current: &PageCursor,
+) -> Option<PageCursor> {
+    match current {
+        PageCursor::Number(page_number) if pagination.as_ref().is_some_and(|p| p.has_more) => {
+            Some(PageCursor::Number(page_number + 1))
+        }
+        _ => None,
+    }
+}
+
+macro_rules! page_numbered {
+    ($($resource:ty => $item:ty),* $(,)?) => {
+        $(
+            impl Paginated for $resource {
+                type Item = $item;
+
+                fn error(&self) -> &ApiError {
+                    &self.error
+                }
+
+                fn next_cursor(&self, current: &PageCursor) -> Option<PageCursor> {
+                    next_page_number(&self.data.pagination, current)
+                }
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.data.items
+                }
+            }
+        )*
+    };
+}
+
+page_numbered!(
+    BalancesData => WalletBalanceItem,
+    TransactionsData => BlockTransactionWithLogEvents,
+    TokenHoldersData => TokenHolderItem,
+    TokenTransfersData => BlockTransactionWithContractTransfers,
+    ChangesInTokenHoldersData => ChangesInTokenHoldersItem,
+);
+
+impl Paginated for LogEventsGenericData {
+    type Item = LogEventItem;
+
+    fn error(&self) -> &ApiError {
+        &self.error
+    }
+
+    fn next_cursor(&self, current: &PageCursor) -> Option<PageCursor> {
+        next_page_number(&self.data.as_ref()?.pagination, current)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data.map(|data| data.items).unwrap_or_default()
+    }
+}
+
+macro_rules! page_linked {
+    ($($resource:ty => $item:ty),* $(,)?) => {
+        $(
+            impl Paginated for $resource {
+                type Item = $item;
+
+                fn error(&self) -> &ApiError {
+                    &self.error
+                }
+
+                fn next_cursor(&self, _current: &PageCursor) -> Option<PageCursor> {
+                    self.data.links.next.clone().map(PageCursor::Link)
+                }
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.data.items
+                }
+            }
+        )*
+    };
+}
+
+page_linked!(
+    TransactionsV3Data => BlockTransactionWithLogEvents,
+    BlockTransactionsData => BlockTransactionWithLogEvents,
+);
+
+/// Stream the items of every page, starting from the first cursor and fetching each page with
+/// `fetch` until a page has no next cursor
+pub fn paginate<'a, P, F, Fut>(
+    first: PageCursor,
+    mut fetch: F,
+) -> impl Stream<Item = Result<P::Item, Box<dyn Error>>> + 'a
+where
+    P: Paginated + 'a,
+    P::Item: 'a,
+    F: FnMut(PageCursor) -> Fut + 'a,
+    Fut: Future<Output = Result<P, Box<dyn Error>>> + 'a,
+{
+    stream::try_unfold(Some(first), move |cursor| {
+        let page = cursor.map(|cursor| (fetch(cursor.clone()), cursor));
+        async move {
+            let (page, cursor) = match page {
+                Some(page) => page,
+                None => return Ok::<_, Box<dyn Error>>(None),
+            };
+            let page = page.await?;
+            page.error().check()?;
+            // a page linking back to itself would otherwise be fetched forever
+            let next = page.next_cursor(&cursor).filter(|next| *next != cursor);
+            let items = page.into_items().into_iter().map(Ok);
+            Ok(Some((stream::iter(items), next)))
+        }
+    })
+    .try_flatten()
+}
+
+impl CovalentClient {
+    /// Stream every transaction of an address, fetching the pages by number
+    pub fn stream_transactions_for_address<'a>(
+        &'a self,
+        addr: &'a str,
+        page_size: Option<String>,
+    ) -> impl Stream<Item = Result<BlockTransactionWithLogEvents, Box<dyn Error>>> + 'a {
+        paginate(PageCursor::Number(0), move |cursor| {
+            let page_size = page_size.clone();
+            async move {
+                match cursor {
+                    PageCursor::Number(page_number) => {
+                        self.get_transactions_for_address(
+                            addr,
+                            page_size,
+                            Some(page_number.to_string()),
+                        )
+                        .await
+                    }
+                    PageCursor::Link(link) => self.get_page_link(&link).await,
+                }
+            }
+        })
+    }
+
+    /// Stream every transaction of an address from the v3 endpoint, following the next page links
+    pub fn stream_transactions_v3<'a>(
+        &'a self,
+        addr: &'a str,
+    ) -> impl Stream<Item = Result<BlockTransactionWithLogEvents, Box<dyn Error>>> + 'a {
+        paginate(PageCursor::Number(0), move |cursor| async move {
+            match cursor {
+                PageCursor::Number(page_number) => {
+                    self.get_transactions_for_address_v3(addr, Some(page_number.to_string()))
+                        .await
+                }
+                PageCursor::Link(link) => self.get_page_link(&link).await,
+            }
+        })
+    }
+
+    /// Stream every transaction in a block, following the next page links
+    pub fn stream_block_transactions<'a>(
+        &'a self,
+        block_height: &'a str,
+    ) -> impl Stream<Item = Result<BlockTransactionWithLogEvents, Box<dyn Error>>> + 'a {
+        paginate(PageCursor::Number(0), move |cursor| async move {
+            match cursor {
+                PageCursor::Number(page_number) => {
+                    self.get_block_transactions(block_height, Some(page_number.to_string()))
+                        .await
+                }
+                PageCursor::Link(link) => self.get_page_link(&link).await,
+            }
+        })
+    }
+
+    /// Stream every holder of a token at a block height, or the latest holders if no height is given
+    pub fn stream_token_holders<'a>(
+        &'a self,
+        token_addr: &'a str,
+        block_height: Option<&'a str>,
+        page_size: Option<String>,
+    ) -> impl Stream<Item = Result<TokenHolderItem, Box<dyn Error>>> + 'a {
+        paginate(PageCursor::Number(0), move |cursor| {
+            let page_size = page_size.clone();
+            async move {
+                match cursor {
+                    PageCursor::Number(page_number) => {
+                        self.get_token_holders_any_bh(
+                            token_addr,
+                            block_height,
+                            page_size,
+                            Some(page_number.to_string()),
+                        )
+                        .await
+                    }
+                    PageCursor::Link(link) => self.get_page_link(&link).await,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::{ApiLinks, TransactionsV3};
+
+    fn v3_page(next: Option<&str>) -> TransactionsV3Data {
+        TransactionsV3Data {
+            data: TransactionsV3 {
+                links: ApiLinks {
+                    prev: None,
+                    next: next.map(str::to_string),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            error: ApiError::default(),
+            extra: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginate_links() {
+        let mut fetched = vec![];
+        let pages: Vec<BlockTransactionWithLogEvents> = paginate(PageCursor::Number(0), |cursor| {
+            fetched.push(cursor.clone());
+            let page = match cursor {
+                PageCursor::Number(_) => v3_page(Some("https://example.com/page/1/")),
+                PageCursor::Link(_) => v3_page(None),
+            };
+            async move { Ok(page) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+        assert!(pages.is_empty());
+        assert_eq!(
+            vec![
+                PageCursor::Number(0),
+                PageCursor::Link("https://example.com/page/1/".to_string())
+            ],
+            fetched
+        );
+    }
+
+    #[test]
+    fn test_block_transactions_links() {
+        let page: BlockTransactionsData = serde_json::from_value(serde_json::json!({
+            "data": {
+                "updated_at": "2023-01-01T00:00:00Z",
+                "current_page": 0,
+                "links": {
+                    "prev": null,
+                    "next": "https://api.covalenthq.com/v1/8217/block/91321208/transactions_v3/page/1/"
+                },
+                "items": []
+            },
+            "error": false,
+            "error_message": null,
+            "error_code": null
+        }))
+        .unwrap();
+        assert_eq!(
+            Some(PageCursor::Link(
+                "https://api.covalenthq.com/v1/8217/block/91321208/transactions_v3/page/1/"
+                    .to_string()
+            )),
+            page.next_cursor(&PageCursor::Number(0))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_error() {
+        let mut page = v3_page(Some("https://example.com/page/1/"));
+        page.error.error = true;
+        page.error.error_message = Some("Rate limited".to_string());
+        let result: Result<Vec<_>, _> = paginate(PageCursor::Number(0), move |_| {
+            let page = page.clone();
+            async move { Ok(page) }
+        })
+        .try_collect()
+        .await;
+        assert_eq!("Rate limited", result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_next_page_number() {
+        let more = Some(ApiPagination {
+            has_more: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            Some(PageCursor::Number(3)),
+            next_page_number(&more, &PageCursor::Number(2))
+        );
+        assert_eq!(None, next_page_number(&None, &PageCursor::Number(2)));
+    }
+}
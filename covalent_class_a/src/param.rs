@@ -0,0 +1,302 @@
+//! Typed values of decoded log event parameters
+//!
+//! Covalent returns the `value` of a decoded parameter as a string, bool or list depending on the
+//! solidity type of the parameter. `ParamValue` uses the parameter type to turn that into a typed
+//! value, and serializes back into the same shape Covalent returns.
+use crate::amount::{parse_amount, I256, U256};
+use serde::ser::SerializeSeq;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParamValue {
+    /// An `address`, as the hex string returned by the API
+    Address(String),
+    /// Any `uint<M>`
+    Uint(U256),
+    /// Any `int<M>`
+    Int(I256),
+    Bool(bool),
+    /// `bytes` or any fixed size `bytes<M>`
+    Bytes(Vec<u8>),
+    String(String),
+    /// A fixed `T[k]` or dynamic `T[]` array
+    Array(Vec<ParamValue>),
+    /// A `tuple` or `(T1,T2,...)` struct
+    Tuple(Vec<ParamValue>),
+    /// A value which did not match its declared type, kept as returned by the API
+    Raw(Value),
+}
+
+impl ParamValue {
+    /// Parse a JSON value returned by the API given the solidity type of the parameter
+    /// Returns `None` for `null`, which Covalent uses for parameters it could not decode
+    pub fn from_json(param_type: &str, value: &Value) -> Option<ParamValue> {
+        if value.is_null() {
+            return None;
+        }
+        let parsed = if let Some(element_type) = array_element_type(param_type) {
+            parse_list(value, |_| element_type).map(ParamValue::Array)
+        } else if param_type.starts_with("tuple") || param_type.starts_with('(') {
+            let component_types = tuple_component_types(param_type);
+            parse_list(value, |i| {
+                component_types
+                    .get(i)
+                    .map(String::as_str)
+                    .unwrap_or_default()
+            })
+            .map(ParamValue::Tuple)
+        } else {
+            parse_scalar(param_type, value)
+        };
+        Some(parsed.unwrap_or_else(|| ParamValue::Raw(value.clone())))
+    }
+
+    pub fn as_address(&self) -> Option<&str> {
+        match self {
+            ParamValue::Address(address) => Some(address),
+            _ => None,
+        }
+    }
+
+    pub fn as_uint(&self) -> Option<U256> {
+        match self {
+            ParamValue::Uint(uint) => Some(*uint),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<I256> {
+        match self {
+            ParamValue::Int(int) => Some(*int),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ParamValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            ParamValue::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ParamValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[ParamValue]> {
+        match self {
+            ParamValue::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_tuple(&self) -> Option<&[ParamValue]> {
+        match self {
+            ParamValue::Tuple(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for ParamValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ParamValue::Address(address) => serializer.serialize_str(address),
+            ParamValue::Uint(uint) => serializer.collect_str(uint),
+            ParamValue::Int(int) => serializer.collect_str(int),
+            ParamValue::Bool(b) => serializer.serialize_bool(*b),
+            ParamValue::Bytes(bytes) => {
+                serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+            }
+            ParamValue::String(s) => serializer.serialize_str(s),
+            ParamValue::Array(values) | ParamValue::Tuple(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(value)?;
+                }
+                seq.end()
+            }
+            ParamValue::Raw(value) => value.serialize(serializer),
+        }
+    }
+}
+
+// values are strings, bools or lists depending on the parameter type, and a `Raw` value can be
+// anything, so the schema only describes them
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ParamValue {
+    fn schema_name() -> String {
+        "ParamValue".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A decoded parameter value, a string for addresses, integers, bytes and \
+                     strings, a bool, or a list for arrays and tuples"
+                        .to_string(),
+                ),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// The element type of an array type like `uint256[]` or `address[2]`
+pub(crate) fn array_element_type(param_type: &str) -> Option<&str> {
+    if !param_type.ends_with(']') {
+        return None;
+    }
+    param_type.rfind('[').map(|i| &param_type[..i])
+}
+
+/// The component types of a tuple type like `(address,uint256[])`, empty for a bare `tuple`
+pub(crate) fn tuple_component_types(param_type: &str) -> Vec<String> {
+    let inner = match param_type
+        .strip_prefix("tuple")
+        .unwrap_or(param_type)
+        .strip_prefix('(')
+        .and_then(|t| t.strip_suffix(')'))
+    {
+        Some(inner) if !inner.is_empty() => inner,
+        _ => return vec![],
+    };
+    let mut components = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                components.push(inner[start..i].to_string());
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    components.push(inner[start..].to_string());
+    components
+}
+
+/// Parse a list value where each element is either a scalar, or a nested decoded parameter
+/// object carrying its own `type` and `value`
+fn parse_list<'a>(
+    value: &Value,
+    element_type: impl Fn(usize) -> &'a str,
+) -> Option<Vec<ParamValue>> {
+    value
+        .as_array()?
+        .iter()
+        .enumerate()
+        .map(|(i, element)| match element {
+            Value::Object(param) if param.contains_key("value") => {
+                let param_type = param
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .unwrap_or_else(|| element_type(i));
+                ParamValue::from_json(param_type, &param["value"])
+            }
+            _ => ParamValue::from_json(element_type(i), element),
+        })
+        .collect()
+}
+
+fn parse_scalar(param_type: &str, value: &Value) -> Option<ParamValue> {
+    match (param_type, value) {
+        ("address", Value::String(address)) => Some(ParamValue::Address(address.clone())),
+        ("bool", Value::Bool(b)) => Some(ParamValue::Bool(*b)),
+        ("bool", Value::String(b)) => b.parse().ok().map(ParamValue::Bool),
+        ("string", Value::String(s)) => Some(ParamValue::String(s.clone())),
+        (t, Value::String(bytes)) if t.starts_with("bytes") => {
+            hex::decode(bytes.trim_start_matches("0x"))
+                .ok()
+                .map(ParamValue::Bytes)
+        }
+        (t, Value::String(_) | Value::Number(_)) if t.starts_with("uint") => {
+            parse_amount(&json_number_string(value))
+                .ok()
+                .map(ParamValue::Uint)
+        }
+        (t, Value::String(_) | Value::Number(_)) if t.starts_with("int") => {
+            parse_amount(&json_number_string(value))
+                .ok()
+                .map(ParamValue::Int)
+        }
+        _ => None,
+    }
+}
+
+fn json_number_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_scalar_values() {
+        assert_eq!(
+            Some(ParamValue::Uint(U256::new(1_000_000))),
+            ParamValue::from_json("uint256", &json!("1000000"))
+        );
+        assert_eq!(
+            Some(ParamValue::Int(I256::new(-5))),
+            ParamValue::from_json("int24", &json!(-5))
+        );
+        assert_eq!(
+            Some(ParamValue::Bytes(vec![0xde, 0xad])),
+            ParamValue::from_json("bytes2", &json!("0xdead"))
+        );
+        assert_eq!(None, ParamValue::from_json("address", &Value::Null));
+        assert_eq!(
+            Some(ParamValue::Raw(json!("not a number"))),
+            ParamValue::from_json("uint256", &json!("not a number"))
+        );
+    }
+
+    #[test]
+    fn test_list_values() {
+        let scalars = ParamValue::from_json("uint256[]", &json!(["1", "2"])).unwrap();
+        let objects = ParamValue::from_json(
+            "uint256[]",
+            &json!([
+                {"name": "ids", "type": "uint256", "indexed": false, "decoded": true, "value": "1"},
+                {"name": "ids", "type": "uint256", "indexed": false, "decoded": true, "value": "2"}
+            ]),
+        )
+        .unwrap();
+        assert_eq!(scalars, objects);
+        assert_eq!(Some(U256::new(2)), scalars.as_array().unwrap()[1].as_uint());
+        assert_eq!(json!(["1", "2"]), serde_json::to_value(&objects).unwrap());
+    }
+
+    #[test]
+    fn test_tuple_component_types() {
+        assert_eq!(
+            vec!["address", "(uint256,bool)", "bytes32[]"],
+            tuple_component_types("(address,(uint256,bool),bytes32[])")
+        );
+        let tuple = ParamValue::from_json("tuple(address,bool)", &json!(["0xabc", true])).unwrap();
+        assert_eq!(Some(true), tuple.as_tuple().unwrap()[1].as_bool());
+    }
+}
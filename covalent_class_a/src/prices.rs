@@ -0,0 +1,130 @@
+//! Lookups on historical token prices
+//!
+//! A `PriceSeries` holds the daily prices of one token from the Get Historical Token Prices
+//! endpoint sorted by date, for looking up the price on a date or interpolating between dates.
+//! ```no_run
+//! use covalent_class_a::prices::PriceSeries;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+//!     let prices = client
+//!         .get_historical_token_prices("0x5c74070fdea071359b86082bd9f9b3deaafbe32b", "USD", Some("2022-05-01"), Some("2022-05-31"), None, None)
+//!         .await
+//!         .unwrap();
+//!     let series = PriceSeries::from_item(&prices.data[0]).unwrap();
+//!     let price = series.price_on(chrono::NaiveDate::from_ymd_opt(2022, 5, 18).unwrap());
+//! }
+//! ```
+use crate::resources::HistoricalPricesItem;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::error::Error;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PriceSeries {
+    pub contract_address: String,
+    pub quote_currency: String,
+    points: Vec<(NaiveDate, f64)>,
+}
+
+impl PriceSeries {
+    /// Create a series from dated prices, dates without a price are skipped
+    pub fn new(
+        contract_address: &str,
+        quote_currency: &str,
+        points: impl IntoIterator<Item = (NaiveDate, f64)>,
+    ) -> PriceSeries {
+        let mut points: Vec<(NaiveDate, f64)> = points.into_iter().collect();
+        points.sort_by_key(|(date, _)| *date);
+        points.dedup_by_key(|(date, _)| *date);
+        PriceSeries {
+            contract_address: contract_address.to_string(),
+            quote_currency: quote_currency.to_string(),
+            points,
+        }
+    }
+
+    /// Create a series from the prices of one contract in a historical prices response
+    pub fn from_item(item: &HistoricalPricesItem) -> Result<PriceSeries, Box<dyn Error>> {
+        let mut points = vec![];
+        for price in &item.prices {
+            if let Some(value) = price.price {
+                points.push((NaiveDate::parse_from_str(&price.date, "%Y-%m-%d")?, value));
+            }
+        }
+        Ok(PriceSeries::new(
+            &item.contract_address,
+            &item.quote_currency,
+            points,
+        ))
+    }
+
+    /// The dated prices in date order
+    pub fn points(&self) -> &[(NaiveDate, f64)] {
+        &self.points
+    }
+
+    /// The price on a date, if the series has a price for that exact date
+    pub fn price_on(&self, date: NaiveDate) -> Option<f64> {
+        self.points
+            .binary_search_by_key(&date, |(d, _)| *d)
+            .ok()
+            .map(|i| self.points[i].1)
+    }
+
+    /// The latest price on or before a date, with the date it was recorded
+    pub fn price_on_or_before(&self, date: NaiveDate) -> Option<(NaiveDate, f64)> {
+        match self.points.binary_search_by_key(&date, |(d, _)| *d) {
+            Ok(i) => Some(self.points[i]),
+            Err(0) => None,
+            Err(i) => Some(self.points[i - 1]),
+        }
+    }
+
+    /// The price at a time, linearly interpolated between the prices of the surrounding dates
+    /// each taken at midnight UTC. `None` outside the dates covered by the series
+    pub fn interpolate(&self, date_time: DateTime<Utc>) -> Option<f64> {
+        let at_midnight = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let (before_date, before_price) = self.price_on_or_before(date_time.date_naive())?;
+        let before_time = at_midnight(before_date);
+        if before_time == date_time {
+            return Some(before_price);
+        }
+        let (after_date, after_price) = self.points.iter().find(|(d, _)| *d > before_date)?;
+        let after_time = at_midnight(*after_date);
+        let fraction = (date_time - before_time).num_seconds() as f64
+            / (after_time - before_time).num_seconds() as f64;
+        Some(before_price + (after_price - before_price) * fraction)
+    }
+
+    /// The price on a date, interpolated from the surrounding dates if the date has no price
+    pub fn interpolate_date(&self, date: NaiveDate) -> Option<f64> {
+        self.interpolate(date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, 5, day).unwrap()
+    }
+
+    #[test]
+    fn test_price_lookups() {
+        let series = PriceSeries::new("0xabc", "USD", vec![(date(20), 3.0), (date(18), 1.0)]);
+        assert_eq!(Some(1.0), series.price_on(date(18)));
+        assert_eq!(None, series.price_on(date(19)));
+        assert_eq!(Some((date(18), 1.0)), series.price_on_or_before(date(19)));
+        assert_eq!(None, series.price_on_or_before(date(17)));
+        assert_eq!(Some(2.0), series.interpolate_date(date(19)));
+        assert_eq!(
+            Some(1.5),
+            series.interpolate(Utc.with_ymd_and_hms(2022, 5, 18, 12, 0, 0).unwrap())
+        );
+        assert_eq!(Some(3.0), series.interpolate_date(date(20)));
+        assert_eq!(None, series.interpolate_date(date(21)));
+    }
+}
@@ -0,0 +1,365 @@
+//! Primer queries for filtering and aggregating on the server
+//!
+//! Covalent endpoints accept Primer query parameters - `match`, `group`, `sort`, `skip`, `limit`
+//! and `primer` - which filter and aggregate the items of a response before it is returned. A
+//! `Primer` attached to a client with `with_primer` is added to every request the client makes.
+//! ```
+//! use covalent_class_a::primer::{Aggregate, Group, Match, Primer, SortOrder};
+//!
+//! let primer = Primer::new()
+//!     .filter(Match::and(vec![
+//!         Match::eq("successful", true),
+//!         Match::gt("value", 0),
+//!     ]))
+//!     .group(Group::by("to_address").aggregate("total_value", Aggregate::Sum("value".to_string())))
+//!     .sort("total_value", SortOrder::Desc)
+//!     .limit(10);
+//! assert!(primer.query_params().starts_with("&match="));
+//! ```
+//! A `group` replaces the items of a response with the groups, which the endpoint's own resource
+//! struct cannot decode, so grouped requests are made with `get_grouped` into a `GroupedData`.
+use crate::drift::Modelled;
+use crate::resources::ApiError;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+
+/// A `match` expression selecting the items to keep
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match(Value);
+
+impl Match {
+    fn operator(field: &str, operator: &str, value: Value) -> Match {
+        Match(json!({ field: { operator: value } }))
+    }
+
+    /// The field equals the value
+    pub fn eq(field: &str, value: impl Into<Value>) -> Match {
+        Match(json!({ field: value.into() }))
+    }
+
+    /// The field does not equal the value
+    pub fn ne(field: &str, value: impl Into<Value>) -> Match {
+        Match::operator(field, "$ne", value.into())
+    }
+
+    /// The field is greater than the value
+    pub fn gt(field: &str, value: impl Into<Value>) -> Match {
+        Match::operator(field, "$gt", value.into())
+    }
+
+    /// The field is greater than or equal to the value
+    pub fn gte(field: &str, value: impl Into<Value>) -> Match {
+        Match::operator(field, "$gte", value.into())
+    }
+
+    /// The field is less than the value
+    pub fn lt(field: &str, value: impl Into<Value>) -> Match {
+        Match::operator(field, "$lt", value.into())
+    }
+
+    /// The field is less than or equal to the value
+    pub fn lte(field: &str, value: impl Into<Value>) -> Match {
+        Match::operator(field, "$lte", value.into())
+    }
+
+    /// The field equals one of the values
+    pub fn is_in<V: Into<Value>>(field: &str, values: impl IntoIterator<Item = V>) -> Match {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        Match::operator(field, "$in", Value::Array(values))
+    }
+
+    /// The field equals none of the values
+    pub fn not_in<V: Into<Value>>(field: &str, values: impl IntoIterator<Item = V>) -> Match {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        Match::operator(field, "$nin", Value::Array(values))
+    }
+
+    /// An element of the array field matches the expression, e.g. a log event of a transaction
+    pub fn elem_match(field: &str, expression: Match) -> Match {
+        Match::operator(field, "$elemMatch", expression.0)
+    }
+
+    /// All of the expressions match
+    pub fn and(expressions: Vec<Match>) -> Match {
+        Match(json!({ "$and": expressions.into_iter().map(|e| e.0).collect::<Vec<_>>() }))
+    }
+
+    /// Any of the expressions match
+    pub fn or(expressions: Vec<Match>) -> Match {
+        Match(json!({ "$or": expressions.into_iter().map(|e| e.0).collect::<Vec<_>>() }))
+    }
+
+    /// A `match` expression written as JSON, e.g. `{"successful": true}`
+    pub fn from_json(expression: &str) -> Result<Match, Box<dyn Error>> {
+        Ok(Match(parse_object(expression, "match")?))
+    }
+
+    pub fn to_json(&self) -> &Value {
+        &self.0
+    }
+}
+
+/// An aggregate computed over each group of items
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregate {
+    Sum(String),
+    Count,
+    Max(String),
+    Min(String),
+    Avg(String),
+    First(String),
+    Last(String),
+    AddToSet(String),
+    Push(String),
+}
+
+impl Aggregate {
+    fn to_json(&self) -> Value {
+        match self {
+            Aggregate::Sum(field) => json!({ "$sum": field }),
+            Aggregate::Count => json!({ "$count": {} }),
+            Aggregate::Max(field) => json!({ "$max": field }),
+            Aggregate::Min(field) => json!({ "$min": field }),
+            Aggregate::Avg(field) => json!({ "$avg": field }),
+            Aggregate::First(field) => json!({ "$first": field }),
+            Aggregate::Last(field) => json!({ "$last": field }),
+            Aggregate::AddToSet(field) => json!({ "$addToSet": field }),
+            Aggregate::Push(field) => json!({ "$push": field }),
+        }
+    }
+}
+
+/// A `group` expression grouping items by a field and computing aggregates for each group
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group(Map<String, Value>);
+
+impl Group {
+    /// Group the items by the value of a field
+    pub fn by(field: &str) -> Group {
+        let mut group = Map::new();
+        group.insert("_id".to_string(), Value::String(field.to_string()));
+        Group(group)
+    }
+
+    /// Add an aggregate computed for each group under a name
+    pub fn aggregate(mut self, name: &str, aggregate: Aggregate) -> Group {
+        self.0.insert(name.to_string(), aggregate.to_json());
+        self
+    }
+
+    /// A `group` expression written as JSON, e.g. `{"_id": "to_address", "count": {"$count": {}}}`
+    pub fn from_json(expression: &str) -> Result<Group, Box<dyn Error>> {
+        match parse_object(expression, "group")? {
+            Value::Object(group) if group.contains_key("_id") => Ok(Group(group)),
+            _ => Err("A group expression requires an _id field to group by".into()),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        Value::Object(self.0.clone())
+    }
+}
+
+fn parse_object(expression: &str, name: &str) -> Result<Value, Box<dyn Error>> {
+    match serde_json::from_str(expression) {
+        Ok(Value::Object(object)) => Ok(Value::Object(object)),
+        Ok(_) => Err(format!("A {} expression must be a JSON object", name).into()),
+        Err(e) => Err(format!("Invalid {} expression {}: {}", name, expression, e).into()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Primer {
+    pub filter: Option<Match>,
+    pub group: Option<Group>,
+    pub sort: Vec<(String, SortOrder)>,
+    pub skip: Option<u64>,
+    pub limit: Option<u64>,
+    // a raw `primer` pipeline of stages, for queries the other parameters cannot express
+    pub pipeline: Option<Value>,
+}
+
+impl Primer {
+    pub fn new() -> Primer {
+        Primer::default()
+    }
+
+    /// Only keep items matching the expression
+    pub fn filter(mut self, expression: Match) -> Primer {
+        self.filter = Some(expression);
+        self
+    }
+
+    /// Group the items and compute aggregates for each group
+    pub fn group(mut self, group: Group) -> Primer {
+        self.group = Some(group);
+        self
+    }
+
+    /// Sort by a field, called again to sort by further fields
+    pub fn sort(mut self, field: &str, order: SortOrder) -> Primer {
+        self.sort.push((field.to_string(), order));
+        self
+    }
+
+    /// Skip this many items
+    pub fn skip(mut self, skip: u64) -> Primer {
+        self.skip = Some(skip);
+        self
+    }
+
+    /// Return at most this many items
+    pub fn limit(mut self, limit: u64) -> Primer {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Run a raw Primer pipeline, a JSON array of stages such as `[{"$match": {...}}]`
+    pub fn pipeline(mut self, pipeline: Value) -> Primer {
+        self.pipeline = Some(pipeline);
+        self
+    }
+
+    /// The Primer query parameters URL-encoded, each prefixed with `&`
+    pub fn query_params(&self) -> String {
+        let mut params = vec![];
+        if let Some(filter) = &self.filter {
+            params.push(("match", filter.to_json().to_string()));
+        }
+        if let Some(group) = &self.group {
+            params.push(("group", group.to_json().to_string()));
+        }
+        if !self.sort.is_empty() {
+            // written by hand as a serde_json Map would reorder the fields, losing their priority
+            let fields: Vec<String> = self
+                .sort
+                .iter()
+                .map(|(field, order)| {
+                    let order = match order {
+                        SortOrder::Asc => 1,
+                        SortOrder::Desc => -1,
+                    };
+                    format!("{}:{}", Value::from(field.as_str()), order)
+                })
+                .collect();
+            params.push(("sort", format!("{{{}}}", fields.join(","))));
+        }
+        if let Some(skip) = self.skip {
+            params.push(("skip", skip.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(pipeline) = &self.pipeline {
+            params.push(("primer", pipeline.to_string()));
+        }
+        params
+            .into_iter()
+            .map(|(name, value)| {
+                format!(
+                    "&{}={}",
+                    name,
+                    utf8_percent_encode(&value, NON_ALPHANUMERIC)
+                )
+            })
+            .collect()
+    }
+}
+
+/// The groups of a response grouped by a Primer `group`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Grouped {
+    // each group is the `_id` it was grouped by and the aggregates computed for it
+    pub items: Vec<Map<String, Value>>,
+    // the other fields of the endpoint's response, such as address and updated_at
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The response of a request grouped by a Primer `group`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GroupedData {
+    pub data: Grouped,
+    #[serde(flatten)]
+    pub error: ApiError,
+}
+
+// the extra fields of a grouped response are the endpoint's own fields rather than drift
+impl Modelled for GroupedData {
+    fn clear_extra(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primer_query_params() {
+        let primer = Primer::new()
+            .filter(Match::is_in("to_address", vec!["0xab", "0xcd"]))
+            .group(Group::by("to_address").aggregate("count", Aggregate::Count))
+            .sort("count", SortOrder::Desc)
+            .limit(5);
+        assert_eq!(
+            "&match=%7B%22to%5Faddress%22%3A%7B%22%24in%22%3A%5B%220xab%22%2C%220xcd%22%5D%7D%7D\
+             &group=%7B%22%5Fid%22%3A%22to%5Faddress%22%2C%22count%22%3A%7B%22%24count%22%3A%7B%7D%7D%7D\
+             &sort=%7B%22count%22%3A%2D1%7D&limit=5",
+            primer.query_params()
+        );
+        assert_eq!("", Primer::new().query_params());
+        let sorted = Primer::new()
+            .sort("value", SortOrder::Desc)
+            .sort("block_height", SortOrder::Asc);
+        assert_eq!(
+            "&sort=%7B%22value%22%3A%2D1%2C%22block%5Fheight%22%3A1%7D",
+            sorted.query_params()
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        assert_eq!(
+            Match::eq("successful", true),
+            Match::from_json(r#"{"successful": true}"#).unwrap()
+        );
+        assert!(Match::from_json("[1]").is_err());
+        assert!(Group::from_json(r#"{"count": {"$count": {}}}"#).is_err());
+    }
+
+    #[test]
+    fn test_grouped_data() {
+        let grouped: GroupedData = serde_json::from_value(json!({
+            "data": {
+                "address": "0xf4024faad5fafd0755875e3161524c9c4e1a1111",
+                "updated_at": "2023-01-01T00:00:00Z",
+                "items": [
+                    {"_id": "0xab", "count": 3},
+                    {"_id": "0xcd", "count": 1}
+                ],
+                "pagination": null
+            },
+            "error": false,
+            "error_message": null,
+            "error_code": null
+        }))
+        .unwrap();
+        assert!(!grouped.error.error);
+        assert_eq!(2, grouped.data.items.len());
+        assert_eq!(Some(&json!("0xab")), grouped.data.items[0].get("_id"));
+        assert_eq!(Some(&json!(3)), grouped.data.items[0].get("count"));
+        assert_eq!(
+            Some(&json!("0xf4024faad5fafd0755875e3161524c9c4e1a1111")),
+            grouped.data.extra.get("address")
+        );
+    }
+}
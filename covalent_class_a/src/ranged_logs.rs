@@ -0,0 +1,307 @@
+//! Log event queries over large block ranges
+//!
+//! Covalent rejects or truncates log event queries over large block ranges. A `RangedLogQuery`
+//! splits the range into windows which are fetched concurrently, every page of each window is
+//! walked, and windows which fail are halved and retried down to a minimum size. The results form
+//! a single stream ordered by `(block_height, tx_offset, log_offset)` with no duplicates.
+//! ```no_run
+//! use covalent_class_a::ranged_logs::RangedLogQuery;
+//! use futures::TryStreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = covalent_class_a::CovalentClient::new_env_api_key("8217").unwrap();
+//!     let query = RangedLogQuery::by_contract("0x5c74070fdea071359b86082bd9f9b3deaafbe32b", 91000000, 91321208)
+//!         .window_size(50000)
+//!         .concurrency(4);
+//!     let log_events: Vec<_> = client.stream_log_events(&query).try_collect().await.unwrap();
+//! }
+//! ```
+use crate::paginate::{paginate, PageCursor};
+use crate::resources::{LogEventItem, LogEventsGenericData};
+use crate::topics::TopicFilter;
+use crate::CovalentClient;
+use futures::future;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use log::warn;
+use std::error::Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogSource {
+    /// Log events emitted by a contract address
+    Contract(String),
+    /// Log events matching a topic filter
+    Topics(TopicFilter),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangedLogQuery {
+    pub source: LogSource,
+    pub starting_block: u64,
+    pub ending_block: u64,
+    pub window_size: u64,
+    pub min_window_size: u64,
+    pub concurrency: usize,
+    pub page_size: Option<String>,
+}
+
+impl RangedLogQuery {
+    pub fn new(source: LogSource, starting_block: u64, ending_block: u64) -> RangedLogQuery {
+        RangedLogQuery {
+            source,
+            starting_block,
+            ending_block,
+            window_size: 10000,
+            min_window_size: 100,
+            concurrency: 4,
+            page_size: None,
+        }
+    }
+
+    /// Query log events emitted by a contract address between two blocks
+    pub fn by_contract(
+        contract_addr: &str,
+        starting_block: u64,
+        ending_block: u64,
+    ) -> RangedLogQuery {
+        RangedLogQuery::new(
+            LogSource::Contract(contract_addr.to_string()),
+            starting_block,
+            ending_block,
+        )
+    }
+
+    /// Query log events matching a topic filter between two blocks
+    pub fn by_topics(
+        filter: TopicFilter,
+        starting_block: u64,
+        ending_block: u64,
+    ) -> RangedLogQuery {
+        RangedLogQuery::new(LogSource::Topics(filter), starting_block, ending_block)
+    }
+
+    /// The number of blocks requested at once, 10000 by default
+    pub fn window_size(mut self, window_size: u64) -> RangedLogQuery {
+        self.window_size = window_size.max(1);
+        self
+    }
+
+    /// The smallest window that failing windows are halved down to before giving up, 100 by default
+    pub fn min_window_size(mut self, min_window_size: u64) -> RangedLogQuery {
+        self.min_window_size = min_window_size.max(1);
+        self
+    }
+
+    /// The number of windows fetched at once, 4 by default
+    pub fn concurrency(mut self, concurrency: usize) -> RangedLogQuery {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// The page size used when walking the pages of each window
+    pub fn page_size(mut self, page_size: &str) -> RangedLogQuery {
+        self.page_size = Some(page_size.to_string());
+        self
+    }
+
+    /// Split the block range into windows
+    ///
+    /// Consecutive windows share their boundary block, so no block is skipped whether the API
+    /// treats `ending-block` as inclusive or exclusive, the overlap is removed when deduplicating
+    pub fn windows(&self) -> Result<Vec<(u64, u64)>, Box<dyn Error>> {
+        if self.starting_block > self.ending_block {
+            return Err(format!(
+                "Starting block {} is after ending block {}",
+                self.starting_block, self.ending_block
+            )
+            .into());
+        }
+        Ok(split_range(
+            self.starting_block,
+            self.ending_block,
+            self.window_size,
+        ))
+    }
+}
+
+fn split_range(starting_block: u64, ending_block: u64, window_size: u64) -> Vec<(u64, u64)> {
+    let mut windows = vec![];
+    let mut start = starting_block;
+    loop {
+        let end = start.saturating_add(window_size).min(ending_block);
+        windows.push((start, end));
+        if end >= ending_block {
+            return windows;
+        }
+        start = end;
+    }
+}
+
+/// The position of a log event used to order and deduplicate them
+pub fn log_position(log: &LogEventItem) -> (u64, u64, u64) {
+    (log.block_height, log.tx_offset, log.log_offset)
+}
+
+/// Drop items from an ordered stream whose key is not greater than the last item let through,
+/// removing the overlap between consecutive windows
+fn dedupe_ordered<T, K: Ord + Copy>(
+    items: impl Stream<Item = Result<T, Box<dyn Error>>>,
+    key: fn(&T) -> K,
+) -> impl Stream<Item = Result<T, Box<dyn Error>>> {
+    items
+        .scan(None, move |last_key, item| {
+            let item = match item {
+                Ok(item) if last_key.is_some_and(|last| key(&item) <= last) => None,
+                Ok(item) => {
+                    *last_key = Some(key(&item));
+                    Some(Ok(item))
+                }
+                Err(e) => Some(Err(e)),
+            };
+            future::ready(Some(item))
+        })
+        .filter_map(future::ready)
+}
+
+impl CovalentClient {
+    /// Stream the log events of a ranged query in block order
+    pub fn stream_log_events<'a>(
+        &'a self,
+        query: &'a RangedLogQuery,
+    ) -> impl Stream<Item = Result<LogEventItem, Box<dyn Error>>> + 'a {
+        let log_events = stream::once(future::ready(query.windows()))
+            .map_ok(|windows| stream::iter(windows.into_iter().map(Ok)))
+            .try_flatten()
+            .map_ok(move |window| self.get_log_events_window(query, window))
+            .try_buffered(query.concurrency)
+            .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+            .try_flatten();
+        dedupe_ordered(log_events, log_position)
+    }
+
+    /// Get all log events of a ranged query in block order
+    pub async fn get_log_events_in_range(
+        &self,
+        query: &RangedLogQuery,
+    ) -> Result<Vec<LogEventItem>, Box<dyn Error>> {
+        self.stream_log_events(query).try_collect().await
+    }
+
+    /// Fetch every page of a window, halving the window on errors down to the minimum window size
+    async fn get_log_events_window(
+        &self,
+        query: &RangedLogQuery,
+        window: (u64, u64),
+    ) -> Result<Vec<LogEventItem>, Box<dyn Error>> {
+        let mut items = vec![];
+        // windows still to fetch, the last is fetched first so push the later half first
+        let mut pending = vec![window];
+        while let Some((start, end)) = pending.pop() {
+            match self.get_log_events_all_pages(query, start, end).await {
+                Ok(window_items) => items.extend(window_items),
+                Err(e) if end.saturating_sub(start) > query.min_window_size => {
+                    warn!(
+                        "Failed to get log events for blocks {}-{}, retrying in smaller windows: {}",
+                        start, end, e
+                    );
+                    let middle = start + (end - start) / 2;
+                    pending.push((middle, end));
+                    pending.push((start, middle));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        items.sort_by_key(log_position);
+        items.dedup_by_key(|item| log_position(item));
+        Ok(items)
+    }
+
+    async fn get_log_events_all_pages(
+        &self,
+        query: &RangedLogQuery,
+        starting_block: u64,
+        ending_block: u64,
+    ) -> Result<Vec<LogEventItem>, Box<dyn Error>> {
+        let (starting_block, ending_block) = (starting_block.to_string(), ending_block.to_string());
+        paginate(PageCursor::Number(0), |cursor| {
+            let (starting_block, ending_block) = (&starting_block, &ending_block);
+            async move {
+                let page_number = match cursor {
+                    PageCursor::Number(page_number) => Some(page_number.to_string()),
+                    PageCursor::Link(link) => return self.get_page_link(&link).await,
+                };
+                let page_size = query.page_size.clone();
+                let resource: LogEventsGenericData = match &query.source {
+                    LogSource::Contract(contract_addr) => {
+                        self.get_log_events_by_contract(
+                            contract_addr,
+                            starting_block,
+                            ending_block,
+                            page_size,
+                            page_number,
+                        )
+                        .await?
+                    }
+                    LogSource::Topics(filter) => {
+                        self.get_log_events_by_topic_filter(
+                            filter,
+                            starting_block,
+                            ending_block,
+                            page_size,
+                            page_number,
+                        )
+                        .await?
+                    }
+                };
+                if resource.data.is_none() && !resource.error.error {
+                    return Err("Log events response is missing data".into());
+                }
+                Ok(resource)
+            }
+        })
+        .try_collect()
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_range() {
+        assert_eq!(vec![(10, 20), (20, 30), (30, 35)], split_range(10, 35, 10));
+        assert_eq!(vec![(10, 10)], split_range(10, 10, 10));
+        let query = RangedLogQuery::by_contract("0xabc", 0, 5).window_size(0);
+        assert_eq!(5, query.windows().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_reversed_range() {
+        let query = RangedLogQuery::by_contract("0xabc", 35, 10);
+        assert!(query.windows().is_err());
+        // rejected before any request is sent
+        let client = CovalentClient::new("8217", "key").unwrap();
+        let result = client.get_log_events_in_range(&query).await;
+        assert_eq!(
+            "Starting block 35 is after ending block 10",
+            result.unwrap_err().to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_ordered() {
+        // the second window repeats the log at the shared boundary block
+        let windows = vec![
+            Ok((10, 0, 0)),
+            Ok((20, 1, 0)),
+            Ok((20, 1, 0)),
+            Ok((20, 1, 1)),
+        ];
+        let items: Vec<(u64, u64, u64)> = dedupe_ordered(stream::iter(windows), |item| *item)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(vec![(10, 0, 0), (20, 1, 0), (20, 1, 1)], items);
+    }
+}
@@ -1,448 +1,361 @@
+use crate::amount::{self, Decimal};
+use crate::drift::Modelled;
+pub use crate::param::ParamValue;
 use serde::{Deserialize, Serialize};
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ApiError {
-    pub error: bool,
-    pub error_message: Option<String>,
-    pub error_code: Option<i32>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ApiPagination {
-    pub has_more: bool,
-    pub page_number: Option<String>,
-    pub page_size: Option<i32>,
-    pub total_count: Option<i32>,
+use serde_json::{Map, Value};
+use std::error::Error;
+
+// the resource structs are generated into resources/generated.rs from spec/covalent.json with
+// `cargo run --manifest-path xtask/Cargo.toml -- codegen`, the structs the generator cannot
+// express and the methods on resources are written here
+mod generated;
+pub use generated::*;
+
+/// A timestamp returned by the API parsed into a `DateTime<Utc>`, as the `chrono` feature is enabled
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+/// A timestamp returned by the API kept as the raw RFC 3339 string e.g. `2022-05-20T10:38:30Z`,
+/// enable the `chrono` feature to have it parsed into a `DateTime<Utc>`
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+impl ApiError {
+    /// Turn an error flagged in a response into an `Err` with the error message
+    pub fn check(&self) -> Result<(), Box<dyn Error>> {
+        if !self.error {
+            return Ok(());
+        }
+        Err(self
+            .error_message
+            .clone()
+            .unwrap_or_else(|| "Unknown API error".to_string())
+            .into())
+    }
 }
 
 // BALANCES
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct WalletBalanceItem {
-    pub contract_decimals: i32,
-    pub contract_name: String,
-    pub contract_ticker_symbol: String,
-    pub contract_address: String,
-    pub supports_erc: Option<Vec<String>>,
-    pub logo_url: String,
-    pub last_transferred_at: Option<String>,
-    #[serde(alias = "type")]
-    pub balance_type: String,
-    pub balance: String,
-    pub balance_24h: Option<String>,
-    pub quote_rate: Option<f64>,
-    pub quote_rate_24h: Option<f64>,
-    pub quote: f64,
-    pub quote_24h: Option<f64>,
-    #[serde(skip_deserializing, skip_serializing)]
-    nft_data: Option<Vec<()>>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct Balances {
-    pub address: String,
-    pub updated_at: String,
-    pub next_update_at: String,
-    pub quote_currency: String,
-    pub chain_id: i64,
-    pub items: Vec<WalletBalanceItem>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
+impl WalletBalanceItem {
+    /// The balance scaled by the contract decimals
+    pub fn balance_decimal(&self) -> Decimal {
+        amount::to_decimal(self.balance, self.contract_decimals)
+    }
+
+    /// The balance 24 hours ago scaled by the contract decimals
+    pub fn balance_24h_decimal(&self) -> Option<Decimal> {
+        self.balance_24h
+            .map(|balance| amount::to_decimal(balance, self.contract_decimals))
+    }
 }
+// END
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct BalancesData {
-    pub data: Balances,
-    #[serde(flatten)]
-    pub error: ApiError,
+// HISTORICAL BALANCES
+impl HistoricalBalanceItem {
+    /// The balance scaled by the contract decimals
+    pub fn balance_decimal(&self) -> Decimal {
+        amount::to_decimal(self.balance, self.contract_decimals)
+    }
 }
 // END
 
 // TOKEN HOLDER
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct TokenHolderItem {
-    pub contract_decimals: i32,
-    pub contract_name: String,
-    pub contract_ticket_symbol: String,
-    pub contract_address: String,
-    pub supports_erc: Option<Vec<String>>,
-    pub logo_url: String,
-    pub address: String,
-    pub balance: i64,
-    pub total_supply: i32,
-    pub block_height: i64,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct TokenHolders {
-    pub updated_at: String,
-    pub items: Vec<TokenHolderItem>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
+impl TokenHolderItem {
+    /// The holders balance scaled by the contract decimals
+    pub fn balance_decimal(&self) -> Decimal {
+        amount::to_decimal(self.balance, self.contract_decimals)
+    }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct TokenHoldersData {
-    pub data: TokenHolders,
-    #[serde(flatten)]
-    pub error: ApiError,
+    /// The total supply scaled by the contract decimals
+    pub fn total_supply_decimal(&self) -> Decimal {
+        amount::to_decimal(self.total_supply, self.contract_decimals)
+    }
 }
 // END
 
 // TRANSACTIONS
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(from = "RawLogDecodedParams")]
 pub struct LogDecodedParams {
-    name: String,
+    pub name: String,
     #[serde(alias = "type")]
     pub param_type: String,
     pub indexed: bool,
     pub decoded: bool,
     // value is usually a String but can sometimes be a Vector(JS sequence/list)
-    // for now avoiding using serde on it because of the type changing
-    #[serde(skip_serializing, skip_deserializing)]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct LogDecoded {
-    pub name: String,
-    pub signature: String,
-    pub params: Option<Vec<LogDecodedParams>>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct LogEventItem {
-    pub block_signed_at: String,
-    pub block_height: i64,
-    pub tx_offset: i64,
-    pub log_offset: i64,
-    pub tx_hash: String,
-    pub raw_log_topics: Option<Vec<String>>,
-    pub sender_contract_decimals: i32,
-    pub sender_name: Option<String>,
-    pub sender_contract_ticker_symbol: Option<String>,
-    pub sender_address: String,
-    pub sender_address_label: Option<String>,
-    pub sender_logo_url: Option<String>,
-    pub raw_log_data: Option<String>,
-    pub decoded: Option<LogDecoded>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct BaseTransaction {
-    pub block_signed_at: String,
-    pub block_height: i32,
-    pub tx_hash: String,
-    pub tx_offset: i32,
-    pub successful: bool,
-    pub from_address: String,
-    pub from_address_label: Option<String>,
-    pub to_address: String,
-    pub to_address_label: Option<String>,
-    pub value: String,
-    pub value_quote: f64,
-    pub gas_offered: i64,
-    pub gas_spent: i64,
-    pub gas_price: i64,
-    pub fees_paid: Option<String>,
-    pub gas_quote: f64,
-    pub gas_quote_rate: f64,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct BlockTransactionWithLogEvents {
-    #[serde(flatten)]
-    pub transaction: BaseTransaction,
-    pub log_events: Option<Vec<LogEventItem>>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct Transactions {
-    pub address: String,
-    pub updated_at: String,
-    pub next_update_at: String,
-    pub quote_currency: String,
-    pub chain_id: i64,
-    pub items: Vec<BlockTransactionWithLogEvents>,
+    // it is parsed into a ParamValue using param_type once the whole param is read
+    pub value: Option<ParamValue>,
     #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct TransactionsData {
-    pub data: Transactions,
-    #[serde(flatten)]
-    pub error: ApiError,
-}
-// END
-
-// TRANSACTION
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct Transaction {
-    pub updated_at: String,
-    pub items: Vec<BlockTransactionWithLogEvents>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct TransactionData {
-    pub data: Transaction,
-    #[serde(flatten)]
-    pub error: ApiError,
-}
-// END
-
-// HISTORICAL PORTFOLIO
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct HoldingsPrice {
-    balance: String,
-    quote: Option<f64>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct Holdings {
-    timestamp: String,
-    quote_rate: Option<f64>,
-    open: HoldingsPrice,
-    high: HoldingsPrice,
-    low: HoldingsPrice,
-    close: HoldingsPrice,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct HistoricalPortfolioItem {
-    pub contract_decimals: i32,
-    pub contract_name: String,
-    pub contract_ticker_symbol: String,
-    pub contract_address: String,
-    pub supports_erc: Option<Vec<String>>,
-    pub logo_url: String,
-    pub holdings: Vec<Holdings>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct HistoricalPortfolio {
-    pub address: String,
-    pub updated_at: String,
-    pub next_update_at: String,
-    pub quote_currency: String,
-    pub chain_id: i64,
-    pub items: Vec<HistoricalPortfolioItem>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct HistoricalPortfolioData {
-    pub data: HistoricalPortfolio,
+#[derive(Deserialize)]
+struct RawLogDecodedParams {
+    name: String,
+    #[serde(alias = "type")]
+    param_type: String,
+    indexed: bool,
+    decoded: bool,
+    #[serde(default)]
+    value: Value,
     #[serde(flatten)]
-    pub error: ApiError,
+    extra: Map<String, Value>,
+}
+
+impl From<RawLogDecodedParams> for LogDecodedParams {
+    fn from(raw: RawLogDecodedParams) -> Self {
+        LogDecodedParams {
+            value: ParamValue::from_json(&raw.param_type, &raw.value),
+            name: raw.name,
+            param_type: raw.param_type,
+            indexed: raw.indexed,
+            decoded: raw.decoded,
+            extra: raw.extra,
+        }
+    }
+}
+
+impl Modelled for LogDecodedParams {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+impl LogDecodedParams {
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_param_type(mut self, param_type: String) -> Self {
+        self.param_type = param_type;
+        self
+    }
+
+    pub fn with_indexed(mut self, indexed: bool) -> Self {
+        self.indexed = indexed;
+        self
+    }
+
+    pub fn with_decoded(mut self, decoded: bool) -> Self {
+        self.decoded = decoded;
+        self
+    }
+
+    pub fn with_value(mut self, value: ParamValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
+impl LogDecoded {
+    /// Get the value of a decoded parameter by name
+    pub fn param(&self, name: &str) -> Option<&ParamValue> {
+        self.params
+            .as_ref()?
+            .iter()
+            .find(|param| param.name == name)?
+            .value
+            .as_ref()
+    }
+}
+
+impl LogEventItem {
+    /// Get the value of a decoded parameter by name, e.g. `param("value")` on a Transfer event
+    pub fn param(&self, name: &str) -> Option<&ParamValue> {
+        self.decoded.as_ref()?.param(name)
+    }
+}
+
+impl BaseTransaction {
+    /// The value transferred scaled by the decimals of the chains native token
+    pub fn value_decimal(&self, native_decimals: i32) -> Decimal {
+        amount::to_decimal(self.value, native_decimals)
+    }
+
+    /// The fees paid scaled by the decimals of the chains native token
+    pub fn fees_paid_decimal(&self, native_decimals: i32) -> Option<Decimal> {
+        self.fees_paid
+            .map(|fees| amount::to_decimal(fees, native_decimals))
+    }
 }
 // END
 
 // ERC20 TOKEN TRANSFERS
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct MethodCallsForTransfers {
-    pub sender_address: String,
-    pub method: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct TokenTransferItem {
-    pub block_signed_at: String,
-    pub tx_hash: String,
-    pub from_address: String,
-    pub from_address_label: Option<String>,
-    pub to_address: String,
-    pub to_address_label: Option<String>,
-    pub contract_decimals: i32,
-    pub contract_name: String,
-    pub contract_ticker_symbol: String,
-    pub contract_address: String,
-    pub logo_url: String,
-    pub transfer_type: String,
-    pub delta: Option<String>,
-    pub balance: Option<f64>,
-    pub quote_rate: Option<f64>,
-    pub delta_quote: Option<f64>,
-    pub balance_quote: Option<f64>,
-    pub method_calls: Option<Vec<MethodCallsForTransfers>>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub struct BlockTransactionWithContractTransfers {
-    #[serde(flatten)]
-    pub transaction: BaseTransaction,
-    pub transfers: Vec<TokenTransferItem>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct TokenTransfers {
-    pub address: String,
-    pub updated_at: String,
-    pub next_update_at: String,
-    pub quote_currency: String,
-    pub chain_id: i64,
-    pub items: Vec<BlockTransactionWithContractTransfers>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct TokenTransfersData {
-    pub data: TokenTransfers,
-    #[serde(flatten)]
-    pub error: ApiError,
+impl TokenTransferItem {
+    /// The transferred amount scaled by the contract decimals
+    pub fn delta_decimal(&self) -> Option<Decimal> {
+        self.delta
+            .map(|delta| amount::to_decimal(delta, self.contract_decimals))
+    }
+
+    /// The balance after the transfer scaled by the contract decimals
+    pub fn balance_decimal(&self) -> Option<Decimal> {
+        self.balance
+            .map(|balance| amount::to_decimal(balance, self.contract_decimals))
+    }
 }
 // END
 
 // CHANGES IN TOKEN HOLDERS
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ChangesInTokenHoldersItem {
-    pub token_holder: String,
-    pub prev_balance: String,
-    pub prev_block_height: i64,
-    pub next_balance: String,
-    pub next_block_height: i64,
-    pub diff: i64,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ChangesInTokenHolders {
-    pub updated_at: String,
-    pub items: Vec<ChangesInTokenHoldersItem>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ChangesInTokenHoldersData {
-    pub data: ChangesInTokenHolders,
-    #[serde(flatten)]
-    pub error: ApiError,
-}
-// END
-
-// GET A BLOCK
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct BlockItem {
-    signed_at: String,
-    height: i32,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct Block {
-    updated_at: String,
-    pub items: Vec<BlockItem>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct BlockData {
-    pub data: Block,
-    #[serde(flatten)]
-    pub error: ApiError,
-}
-// END
-
-// LOG EVENTS GENERIC
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct LogEventsGeneric {
-    pub updated_at: String,
-    pub items: Vec<LogEventItem>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct LogEventsGenericData {
-    pub data: Option<LogEventsGeneric>,
-    #[serde(flatten)]
-    pub error: ApiError,
+impl ChangesInTokenHoldersItem {
+    /// The balance change scaled by the contract decimals of the token
+    pub fn diff_decimal(&self, contract_decimals: i32) -> Decimal {
+        amount::to_decimal_signed(self.diff, contract_decimals)
+    }
 }
 // END
 
 // CONTRACT METADATA
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ContractMetadataItem {
-    pub contract_decimals: i32,
-    pub contract_name: Option<String>,
-    pub contract_ticker_symbol: Option<String>,
-    pub contract_address: String,
-    pub supports_erc: Option<Vec<String>>,
-    pub logo_url: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ContractMetadata {
-    pub updated_at: String,
-    // NOTE: maybe slight bug in unified API? will report this in my submission
-    // items is returned inside a doubled up list like [[ ... ]]
-    // requires 2 Vec<Vec<>> to deserialize
-    pub items: Vec<Vec<ContractMetadataItem>>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct ContractMetadataData {
-    pub data: ContractMetadata,
-    #[serde(flatten)]
-    pub error: ApiError,
+impl ContractMetadata {
+    /// The items without the doubled up list the API returns them in
+    pub fn items(&self) -> impl Iterator<Item = &ContractMetadataItem> {
+        self.items.iter().flatten()
+    }
 }
 // END
 
-// ALL CHAINS
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct GenericChainInfoDisplay {
-    pub name: String,
-    pub chain_id: String,
-    pub is_testnet: bool,
-    pub db_schema_name: String,
-    pub label: String,
-    pub logo_url: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct AllChain {
-    pub updated_at: String,
-    pub items: Vec<GenericChainInfoDisplay>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct AllChainData {
-    pub data: AllChain,
-    #[serde(flatten)]
-    pub error: ApiError,
+// XY=K POOLS
+impl XykPoolToken {
+    /// The reserve of this token in the pool scaled by the contract decimals
+    pub fn reserve_decimal(&self) -> Option<Decimal> {
+        self.reserve
+            .map(|reserve| amount::to_decimal(reserve, self.contract_decimals))
+    }
 }
 // END
 
-// All CHAINS STATUSES
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct GenericChainInfoStatusDisplay {
-    pub name: String,
-    pub chain_id: String,
-    pub is_testnet: bool,
-    pub logo_url: String,
-    pub synced_block_height: i32,
-    pub synced_blocked_signed_at: String,
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct AllChainStatuses {
-    pub updated_at: String,
-    pub items: Vec<GenericChainInfoStatusDisplay>,
-    #[serde(flatten)]
-    pub pagination: Option<ApiPagination>,
+// XY=K ADDRESS EXCHANGE BALANCES
+impl XykBalanceToken {
+    /// The balance scaled by the contract decimals
+    pub fn balance_decimal(&self) -> Option<Decimal> {
+        self.balance
+            .map(|balance| amount::to_decimal(balance, self.contract_decimals))
+    }
 }
+// END
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-pub struct AllChainStatusesData {
-    pub data: AllChainStatuses,
-    #[serde(flatten)]
-    pub error: ApiError,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::U256;
+
+    #[test]
+    fn test_timestamp_round_trip() {
+        let json = r#"{"updated_at":"2022-05-20T10:38:30.927434011Z","items":[{"signed_at":"2022-05-18T00:00:01Z","height":91321199,"block_hash":"0x5fd4e4c2d0c1a8a1bb3bbcf2ba69e3c1f3e4ad1f5f9a61e4b4f0a6b3c1d2e3f4","block_parent_hash":null,"extra_data":null,"miner_address":"0x0000000000000000000000000000000000000000","mining_cost":null,"gas_used":1203451,"gas_limit":null,"transactions_link":null}],"has_more":false,"page_number":null,"page_size":null,"total_count":null}"#;
+        let block: Block = serde_json::from_str(json).unwrap();
+        assert_eq!(json, serde_json::to_string(&block).unwrap());
+    }
+
+    #[test]
+    fn test_log_decoded_params() {
+        let json = r#"{"name":"Transfer","signature":"Transfer(indexed address from, indexed address to, uint256 value)","params":[{"name":"from","type":"address","indexed":true,"decoded":true,"value":"0x0000000000000000000000000000000000000000"},{"name":"value","type":"uint256","indexed":false,"decoded":true,"value":"5000000000000000000"}]}"#;
+        let decoded: LogDecoded = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            Some("0x0000000000000000000000000000000000000000"),
+            decoded.param("from").and_then(ParamValue::as_address)
+        );
+        assert_eq!(
+            Some(U256::new(5_000_000_000_000_000_000)),
+            decoded.param("value").and_then(ParamValue::as_uint)
+        );
+        assert_eq!(None, decoded.param("to"));
+    }
+
+    #[test]
+    fn test_unknown_fields_captured() {
+        let json = r#"{"signed_at":"2022-05-18T00:00:01Z","height":91321199,"block_hash":null,"block_parent_hash":null,"extra_data":null,"miner_address":null,"mining_cost":null,"gas_used":null,"gas_limit":null,"transactions_link":null,"base_fee":"25000000000"}"#;
+        let item: BlockItem = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            Some(&Value::from("25000000000")),
+            item.extra.get("base_fee")
+        );
+        assert_eq!(json, serde_json::to_string(&item).unwrap());
+
+        let page = r#"{"data":{"updated_at":"2022-05-20T10:38:30Z","items":[],"has_more":false,"page_number":null,"page_size":null,"total_count":null},"error":false,"error_message":null,"error_code":null,"cached":true}"#;
+        let block: BlockData = serde_json::from_str(page).unwrap();
+        assert_eq!(1, block.extra.len());
+        assert!(block.data.extra.is_empty());
+        assert_eq!(Some(&Value::Bool(true)), block.extra.get("cached"));
+    }
+
+    #[test]
+    fn test_nft_data() {
+        let json = r#"{"token_id":"123","token_balance":"1","token_url":null,"supports_erc":["erc721"],"token_price_wei":null,"token_quote_rate_eth":null,"original_owner":"0xf4024faad5fafd0755875e3161524c9c4e1a1111","external_data":{"name":"Meebit #123","image":"https://example.com/123.png","attributes":[{"trait_type":"Type","value":"Human"},{"trait_type":"Level","value":3}]},"owner":null,"owner_address":null,"burned":null}"#;
+        let nft: NftData = serde_json::from_str(json).unwrap();
+        assert_eq!(Some(U256::new(123)), nft.token_id);
+        let attributes = nft.external_data.unwrap().attributes.unwrap();
+        assert_eq!(serde_json::json!(3), attributes[1].value);
+    }
+
+    #[test]
+    fn test_xyk_pool() {
+        let json = r#"{"dex_name":"uniswap_v2","exchange":"0x21b8065d10f73ee2e260e5b47d3344d3ced7596e","swap_count_24h":120,"total_liquidity_quote":1000.5,"volume_24h_quote":null,"fee_24h_quote":null,"total_supply":"1000000","quote_rate":null,"token_0":{"contract_decimals":6,"contract_ticker_symbol":"USDC","contract_address":"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48","reserve":"2500000","quote_rate":1.0},"token_1":{"contract_decimals":18,"contract_ticker_symbol":"WETH","contract_address":"0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2","reserve":null}}"#;
+        let pool: XykPoolItem = serde_json::from_str(json).unwrap();
+        assert_eq!(Some(U256::new(1_000_000)), pool.total_supply);
+        assert_eq!(Some("2.5".parse().unwrap()), pool.token_0.reserve_decimal());
+        assert_eq!(None, pool.token_1.reserve_decimal());
+    }
+
+    #[test]
+    fn test_builders() {
+        let decoded = LogDecoded::default()
+            .with_name("Transfer".to_string())
+            .with_params(vec![LogDecodedParams::default()
+                .with_name("value".to_string())
+                .with_param_type("uint256".to_string())
+                .with_decoded(true)
+                .with_value(ParamValue::Uint(U256::new(5)))]);
+        assert_eq!(
+            Some(&ParamValue::Uint(U256::new(5))),
+            decoded.param("value")
+        );
+
+        let holder = TokenHolderItem::default()
+            .with_contract_decimals(2)
+            .with_balance(U256::new(150))
+            .with_supports_erc(vec!["erc20".to_string()]);
+        assert_eq!("1.5".parse::<Decimal>().unwrap(), holder.balance_decimal());
+        // optional fields are set to Some
+        assert_eq!(Some(vec!["erc20".to_string()]), holder.supports_erc);
+    }
+
+    #[test]
+    fn test_contract_metadata_items() {
+        let item = |address: &str| {
+            ContractMetadataItem::default().with_contract_address(address.to_string())
+        };
+        let metadata = ContractMetadata::default()
+            .with_items(vec![vec![item("0x01"), item("0x02")], vec![item("0x03")]]);
+        let addresses: Vec<&str> = metadata
+            .items()
+            .map(|item| item.contract_address.as_str())
+            .collect();
+        assert_eq!(vec!["0x01", "0x02", "0x03"], addresses);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timestamp_parsed() {
+        use chrono::{TimeZone, Utc};
+        let item: BlockItem =
+            serde_json::from_str(r#"{"signed_at":"2022-05-18T00:00:01Z","height":91321199}"#)
+                .unwrap();
+        assert_eq!(
+            Utc.with_ymd_and_hms(2022, 5, 18, 0, 0, 1).unwrap(),
+            item.signed_at
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        let schema = serde_json::to_value(json_schema("TokenHolderItem").unwrap()).unwrap();
+        // amounts are described as the strings they are in the JSON
+        assert_eq!(
+            serde_json::json!({"type": "string"}),
+            schema["properties"]["balance"]
+        );
+        assert!(json_schema("LogDecodedParams").is_some());
+        assert!(json_schema("NotAResource").is_none());
+    }
 }
-// END
@@ -0,0 +1,5021 @@
+// @generated by `cargo run --manifest-path xtask/Cargo.toml -- codegen` from
+// spec/covalent.json and spec/overrides.json, edit those rather than this file
+use super::{LogDecodedParams, Timestamp};
+use crate::amount::{self, I256, U256};
+use crate::drift::Modelled;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ApiError {
+    pub error: bool,
+    pub error_message: Option<String>,
+    pub error_code: Option<i32>,
+}
+
+impl ApiError {
+    pub fn with_error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+
+    pub fn with_error_message(mut self, error_message: String) -> Self {
+        self.error_message = Some(error_message);
+        self
+    }
+
+    pub fn with_error_code(mut self, error_code: i32) -> Self {
+        self.error_code = Some(error_code);
+        self
+    }
+}
+
+impl Modelled for ApiError {
+    fn clear_extra(&mut self) {}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ApiPagination {
+    pub has_more: bool,
+    pub page_number: Option<String>,
+    pub page_size: Option<i32>,
+    pub total_count: Option<i32>,
+}
+
+impl ApiPagination {
+    pub fn with_has_more(mut self, has_more: bool) -> Self {
+        self.has_more = has_more;
+        self
+    }
+
+    pub fn with_page_number(mut self, page_number: String) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn with_page_size(mut self, page_size: i32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = Some(total_count);
+        self
+    }
+}
+
+impl Modelled for ApiPagination {
+    fn clear_extra(&mut self) {}
+}
+
+/// Links to the previous and next pages of the newer endpoints which page by URL path
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ApiLinks {
+    pub prev: Option<String>,
+    pub next: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ApiLinks {
+    pub fn with_prev(mut self, prev: String) -> Self {
+        self.prev = Some(prev);
+        self
+    }
+
+    pub fn with_next(mut self, next: String) -> Self {
+        self.next = Some(next);
+        self
+    }
+}
+
+impl Modelled for ApiLinks {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WalletBalanceItem {
+    pub contract_decimals: i32,
+    pub contract_name: String,
+    pub contract_ticker_symbol: String,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: String,
+    pub last_transferred_at: Option<Timestamp>,
+    #[serde(alias = "type")]
+    pub balance_type: String,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub balance: U256,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub balance_24h: Option<U256>,
+    pub quote_rate: Option<f64>,
+    pub quote_rate_24h: Option<f64>,
+    pub quote: f64,
+    pub quote_24h: Option<f64>,
+    pub nft_data: Option<Vec<NftData>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl WalletBalanceItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = contract_name;
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = contract_ticker_symbol;
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = logo_url;
+        self
+    }
+
+    pub fn with_last_transferred_at(mut self, last_transferred_at: Timestamp) -> Self {
+        self.last_transferred_at = Some(last_transferred_at);
+        self
+    }
+
+    pub fn with_balance_type(mut self, balance_type: String) -> Self {
+        self.balance_type = balance_type;
+        self
+    }
+
+    pub fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    pub fn with_balance_24h(mut self, balance_24h: U256) -> Self {
+        self.balance_24h = Some(balance_24h);
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+
+    pub fn with_quote_rate_24h(mut self, quote_rate_24h: f64) -> Self {
+        self.quote_rate_24h = Some(quote_rate_24h);
+        self
+    }
+
+    pub fn with_quote(mut self, quote: f64) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    pub fn with_quote_24h(mut self, quote_24h: f64) -> Self {
+        self.quote_24h = Some(quote_24h);
+        self
+    }
+
+    pub fn with_nft_data(mut self, nft_data: Vec<NftData>) -> Self {
+        self.nft_data = Some(nft_data);
+        self
+    }
+}
+
+impl Modelled for WalletBalanceItem {
+    fn clear_extra(&mut self) {
+        self.nft_data.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Balances {
+    pub address: String,
+    pub updated_at: Timestamp,
+    pub next_update_at: Timestamp,
+    pub quote_currency: String,
+    pub chain_id: i64,
+    pub items: Vec<WalletBalanceItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Balances {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_next_update_at(mut self, next_update_at: Timestamp) -> Self {
+        self.next_update_at = next_update_at;
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = quote_currency;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<WalletBalanceItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for Balances {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BalancesData {
+    pub data: Balances,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BalancesData {
+    pub fn with_data(mut self, data: Balances) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for BalancesData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalBalanceItem {
+    pub contract_decimals: i32,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: Option<String>,
+    pub block_height: Option<u64>,
+    pub last_transferred_block_height: Option<u64>,
+    pub last_transferred_at: Option<Timestamp>,
+    #[serde(alias = "type")]
+    pub balance_type: Option<String>,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub balance: U256,
+    pub quote_rate: Option<f64>,
+    pub quote: Option<f64>,
+    pub nft_data: Option<Vec<NftData>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalBalanceItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.block_height = Some(block_height);
+        self
+    }
+
+    pub fn with_last_transferred_block_height(
+        mut self,
+        last_transferred_block_height: u64,
+    ) -> Self {
+        self.last_transferred_block_height = Some(last_transferred_block_height);
+        self
+    }
+
+    pub fn with_last_transferred_at(mut self, last_transferred_at: Timestamp) -> Self {
+        self.last_transferred_at = Some(last_transferred_at);
+        self
+    }
+
+    pub fn with_balance_type(mut self, balance_type: String) -> Self {
+        self.balance_type = Some(balance_type);
+        self
+    }
+
+    pub fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+
+    pub fn with_quote(mut self, quote: f64) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    pub fn with_nft_data(mut self, nft_data: Vec<NftData>) -> Self {
+        self.nft_data = Some(nft_data);
+        self
+    }
+}
+
+impl Modelled for HistoricalBalanceItem {
+    fn clear_extra(&mut self) {
+        self.nft_data.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalBalances {
+    pub address: String,
+    pub updated_at: Timestamp,
+    pub next_update_at: Option<Timestamp>,
+    pub quote_currency: String,
+    pub chain_id: i64,
+    pub items: Vec<HistoricalBalanceItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalBalances {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_next_update_at(mut self, next_update_at: Timestamp) -> Self {
+        self.next_update_at = Some(next_update_at);
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = quote_currency;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<HistoricalBalanceItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for HistoricalBalances {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalBalancesData {
+    pub data: HistoricalBalances,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalBalancesData {
+    pub fn with_data(mut self, data: HistoricalBalances) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for HistoricalBalancesData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftAttribute {
+    pub trait_type: Option<String>,
+    /// Attribute values are strings or numbers depending on the collection
+    pub value: Value,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftAttribute {
+    pub fn with_trait_type(mut self, trait_type: String) -> Self {
+        self.trait_type = Some(trait_type);
+        self
+    }
+
+    pub fn with_value(mut self, value: Value) -> Self {
+        self.value = value;
+        self
+    }
+}
+
+impl Modelled for NftAttribute {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftExternalData {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub image_256: Option<String>,
+    pub image_512: Option<String>,
+    pub image_1024: Option<String>,
+    pub animation_url: Option<String>,
+    pub external_url: Option<String>,
+    pub attributes: Option<Vec<NftAttribute>>,
+    pub owner: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftExternalData {
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn with_image(mut self, image: String) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    pub fn with_image_256(mut self, image_256: String) -> Self {
+        self.image_256 = Some(image_256);
+        self
+    }
+
+    pub fn with_image_512(mut self, image_512: String) -> Self {
+        self.image_512 = Some(image_512);
+        self
+    }
+
+    pub fn with_image_1024(mut self, image_1024: String) -> Self {
+        self.image_1024 = Some(image_1024);
+        self
+    }
+
+    pub fn with_animation_url(mut self, animation_url: String) -> Self {
+        self.animation_url = Some(animation_url);
+        self
+    }
+
+    pub fn with_external_url(mut self, external_url: String) -> Self {
+        self.external_url = Some(external_url);
+        self
+    }
+
+    pub fn with_attributes(mut self, attributes: Vec<NftAttribute>) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+
+    pub fn with_owner(mut self, owner: String) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+}
+
+impl Modelled for NftExternalData {
+    fn clear_extra(&mut self) {
+        self.attributes.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftData {
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub token_id: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub token_balance: Option<U256>,
+    pub token_url: Option<String>,
+    pub supports_erc: Option<Vec<String>>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub token_price_wei: Option<U256>,
+    pub token_quote_rate_eth: Option<String>,
+    pub original_owner: Option<String>,
+    pub external_data: Option<NftExternalData>,
+    pub owner: Option<String>,
+    pub owner_address: Option<String>,
+    pub burned: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftData {
+    pub fn with_token_id(mut self, token_id: U256) -> Self {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    pub fn with_token_balance(mut self, token_balance: U256) -> Self {
+        self.token_balance = Some(token_balance);
+        self
+    }
+
+    pub fn with_token_url(mut self, token_url: String) -> Self {
+        self.token_url = Some(token_url);
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_token_price_wei(mut self, token_price_wei: U256) -> Self {
+        self.token_price_wei = Some(token_price_wei);
+        self
+    }
+
+    pub fn with_token_quote_rate_eth(mut self, token_quote_rate_eth: String) -> Self {
+        self.token_quote_rate_eth = Some(token_quote_rate_eth);
+        self
+    }
+
+    pub fn with_original_owner(mut self, original_owner: String) -> Self {
+        self.original_owner = Some(original_owner);
+        self
+    }
+
+    pub fn with_external_data(mut self, external_data: NftExternalData) -> Self {
+        self.external_data = Some(external_data);
+        self
+    }
+
+    pub fn with_owner(mut self, owner: String) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    pub fn with_owner_address(mut self, owner_address: String) -> Self {
+        self.owner_address = Some(owner_address);
+        self
+    }
+
+    pub fn with_burned(mut self, burned: bool) -> Self {
+        self.burned = Some(burned);
+        self
+    }
+}
+
+impl Modelled for NftData {
+    fn clear_extra(&mut self) {
+        self.external_data.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenHolderItem {
+    pub contract_decimals: i32,
+    pub contract_name: String,
+    pub contract_ticker_symbol: String,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: String,
+    pub address: String,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub balance: U256,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub total_supply: U256,
+    pub block_height: u64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TokenHolderItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = contract_name;
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = contract_ticker_symbol;
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = logo_url;
+        self
+    }
+
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    pub fn with_total_supply(mut self, total_supply: U256) -> Self {
+        self.total_supply = total_supply;
+        self
+    }
+
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.block_height = block_height;
+        self
+    }
+}
+
+impl Modelled for TokenHolderItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenHolders {
+    pub updated_at: Timestamp,
+    pub items: Vec<TokenHolderItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TokenHolders {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<TokenHolderItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for TokenHolders {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenHoldersData {
+    pub data: TokenHolders,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TokenHoldersData {
+    pub fn with_data(mut self, data: TokenHolders) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for TokenHoldersData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LogDecoded {
+    pub name: String,
+    pub signature: String,
+    pub params: Option<Vec<LogDecodedParams>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl LogDecoded {
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_signature(mut self, signature: String) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    pub fn with_params(mut self, params: Vec<LogDecodedParams>) -> Self {
+        self.params = Some(params);
+        self
+    }
+}
+
+impl Modelled for LogDecoded {
+    fn clear_extra(&mut self) {
+        self.params.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LogEventItem {
+    pub block_signed_at: Timestamp,
+    pub block_height: u64,
+    pub tx_offset: u64,
+    pub log_offset: u64,
+    pub tx_hash: String,
+    pub raw_log_topics: Option<Vec<String>>,
+    pub sender_contract_decimals: i32,
+    pub sender_name: Option<String>,
+    pub sender_contract_ticker_symbol: Option<String>,
+    pub sender_address: String,
+    pub sender_address_label: Option<String>,
+    pub sender_logo_url: Option<String>,
+    pub raw_log_data: Option<String>,
+    pub decoded: Option<LogDecoded>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl LogEventItem {
+    pub fn with_block_signed_at(mut self, block_signed_at: Timestamp) -> Self {
+        self.block_signed_at = block_signed_at;
+        self
+    }
+
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.block_height = block_height;
+        self
+    }
+
+    pub fn with_tx_offset(mut self, tx_offset: u64) -> Self {
+        self.tx_offset = tx_offset;
+        self
+    }
+
+    pub fn with_log_offset(mut self, log_offset: u64) -> Self {
+        self.log_offset = log_offset;
+        self
+    }
+
+    pub fn with_tx_hash(mut self, tx_hash: String) -> Self {
+        self.tx_hash = tx_hash;
+        self
+    }
+
+    pub fn with_raw_log_topics(mut self, raw_log_topics: Vec<String>) -> Self {
+        self.raw_log_topics = Some(raw_log_topics);
+        self
+    }
+
+    pub fn with_sender_contract_decimals(mut self, sender_contract_decimals: i32) -> Self {
+        self.sender_contract_decimals = sender_contract_decimals;
+        self
+    }
+
+    pub fn with_sender_name(mut self, sender_name: String) -> Self {
+        self.sender_name = Some(sender_name);
+        self
+    }
+
+    pub fn with_sender_contract_ticker_symbol(
+        mut self,
+        sender_contract_ticker_symbol: String,
+    ) -> Self {
+        self.sender_contract_ticker_symbol = Some(sender_contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_sender_address(mut self, sender_address: String) -> Self {
+        self.sender_address = sender_address;
+        self
+    }
+
+    pub fn with_sender_address_label(mut self, sender_address_label: String) -> Self {
+        self.sender_address_label = Some(sender_address_label);
+        self
+    }
+
+    pub fn with_sender_logo_url(mut self, sender_logo_url: String) -> Self {
+        self.sender_logo_url = Some(sender_logo_url);
+        self
+    }
+
+    pub fn with_raw_log_data(mut self, raw_log_data: String) -> Self {
+        self.raw_log_data = Some(raw_log_data);
+        self
+    }
+
+    pub fn with_decoded(mut self, decoded: LogDecoded) -> Self {
+        self.decoded = Some(decoded);
+        self
+    }
+}
+
+impl Modelled for LogEventItem {
+    fn clear_extra(&mut self) {
+        self.decoded.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BaseTransaction {
+    pub block_signed_at: Timestamp,
+    pub block_height: u64,
+    pub tx_hash: String,
+    pub tx_offset: u64,
+    pub successful: bool,
+    pub from_address: String,
+    pub from_address_label: Option<String>,
+    pub to_address: String,
+    pub to_address_label: Option<String>,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub value: U256,
+    pub value_quote: f64,
+    pub gas_offered: u64,
+    pub gas_spent: u64,
+    pub gas_price: u64,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub fees_paid: Option<U256>,
+    pub gas_quote: f64,
+    pub gas_quote_rate: f64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BaseTransaction {
+    pub fn with_block_signed_at(mut self, block_signed_at: Timestamp) -> Self {
+        self.block_signed_at = block_signed_at;
+        self
+    }
+
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.block_height = block_height;
+        self
+    }
+
+    pub fn with_tx_hash(mut self, tx_hash: String) -> Self {
+        self.tx_hash = tx_hash;
+        self
+    }
+
+    pub fn with_tx_offset(mut self, tx_offset: u64) -> Self {
+        self.tx_offset = tx_offset;
+        self
+    }
+
+    pub fn with_successful(mut self, successful: bool) -> Self {
+        self.successful = successful;
+        self
+    }
+
+    pub fn with_from_address(mut self, from_address: String) -> Self {
+        self.from_address = from_address;
+        self
+    }
+
+    pub fn with_from_address_label(mut self, from_address_label: String) -> Self {
+        self.from_address_label = Some(from_address_label);
+        self
+    }
+
+    pub fn with_to_address(mut self, to_address: String) -> Self {
+        self.to_address = to_address;
+        self
+    }
+
+    pub fn with_to_address_label(mut self, to_address_label: String) -> Self {
+        self.to_address_label = Some(to_address_label);
+        self
+    }
+
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn with_value_quote(mut self, value_quote: f64) -> Self {
+        self.value_quote = value_quote;
+        self
+    }
+
+    pub fn with_gas_offered(mut self, gas_offered: u64) -> Self {
+        self.gas_offered = gas_offered;
+        self
+    }
+
+    pub fn with_gas_spent(mut self, gas_spent: u64) -> Self {
+        self.gas_spent = gas_spent;
+        self
+    }
+
+    pub fn with_gas_price(mut self, gas_price: u64) -> Self {
+        self.gas_price = gas_price;
+        self
+    }
+
+    pub fn with_fees_paid(mut self, fees_paid: U256) -> Self {
+        self.fees_paid = Some(fees_paid);
+        self
+    }
+
+    pub fn with_gas_quote(mut self, gas_quote: f64) -> Self {
+        self.gas_quote = gas_quote;
+        self
+    }
+
+    pub fn with_gas_quote_rate(mut self, gas_quote_rate: f64) -> Self {
+        self.gas_quote_rate = gas_quote_rate;
+        self
+    }
+}
+
+impl Modelled for BaseTransaction {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockTransactionWithLogEvents {
+    #[serde(flatten)]
+    pub transaction: BaseTransaction,
+    pub log_events: Option<Vec<LogEventItem>>,
+}
+
+impl BlockTransactionWithLogEvents {
+    pub fn with_transaction(mut self, transaction: BaseTransaction) -> Self {
+        self.transaction = transaction;
+        self
+    }
+
+    pub fn with_log_events(mut self, log_events: Vec<LogEventItem>) -> Self {
+        self.log_events = Some(log_events);
+        self
+    }
+}
+
+impl Modelled for BlockTransactionWithLogEvents {
+    fn clear_extra(&mut self) {
+        self.transaction.clear_extra();
+        self.log_events.clear_extra();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Transactions {
+    pub address: String,
+    pub updated_at: Timestamp,
+    pub next_update_at: Timestamp,
+    pub quote_currency: String,
+    pub chain_id: i64,
+    pub items: Vec<BlockTransactionWithLogEvents>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Transactions {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_next_update_at(mut self, next_update_at: Timestamp) -> Self {
+        self.next_update_at = next_update_at;
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = quote_currency;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<BlockTransactionWithLogEvents>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for Transactions {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionsData {
+    pub data: Transactions,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionsData {
+    pub fn with_data(mut self, data: Transactions) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for TransactionsData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionsV3 {
+    pub address: String,
+    pub updated_at: Timestamp,
+    pub next_update_at: Option<Timestamp>,
+    pub quote_currency: String,
+    pub chain_id: i64,
+    pub chain_name: Option<String>,
+    pub current_page: Option<u64>,
+    pub links: ApiLinks,
+    pub items: Vec<BlockTransactionWithLogEvents>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionsV3 {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_next_update_at(mut self, next_update_at: Timestamp) -> Self {
+        self.next_update_at = Some(next_update_at);
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = quote_currency;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_chain_name(mut self, chain_name: String) -> Self {
+        self.chain_name = Some(chain_name);
+        self
+    }
+
+    pub fn with_current_page(mut self, current_page: u64) -> Self {
+        self.current_page = Some(current_page);
+        self
+    }
+
+    pub fn with_links(mut self, links: ApiLinks) -> Self {
+        self.links = links;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<BlockTransactionWithLogEvents>) -> Self {
+        self.items = items;
+        self
+    }
+}
+
+impl Modelled for TransactionsV3 {
+    fn clear_extra(&mut self) {
+        self.links.clear_extra();
+        self.items.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionsV3Data {
+    pub data: TransactionsV3,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionsV3Data {
+    pub fn with_data(mut self, data: TransactionsV3) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for TransactionsV3Data {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Transaction {
+    pub updated_at: Timestamp,
+    pub items: Vec<BlockTransactionWithLogEvents>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Transaction {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<BlockTransactionWithLogEvents>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for Transaction {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionData {
+    pub data: Transaction,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionData {
+    pub fn with_data(mut self, data: Transaction) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for TransactionData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HoldingsPrice {
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub balance: U256,
+    pub quote: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HoldingsPrice {
+    pub fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    pub fn with_quote(mut self, quote: f64) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+}
+
+impl Modelled for HoldingsPrice {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Holdings {
+    pub timestamp: Timestamp,
+    pub quote_rate: Option<f64>,
+    pub open: HoldingsPrice,
+    pub high: HoldingsPrice,
+    pub low: HoldingsPrice,
+    pub close: HoldingsPrice,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Holdings {
+    pub fn with_timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+
+    pub fn with_open(mut self, open: HoldingsPrice) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn with_high(mut self, high: HoldingsPrice) -> Self {
+        self.high = high;
+        self
+    }
+
+    pub fn with_low(mut self, low: HoldingsPrice) -> Self {
+        self.low = low;
+        self
+    }
+
+    pub fn with_close(mut self, close: HoldingsPrice) -> Self {
+        self.close = close;
+        self
+    }
+}
+
+impl Modelled for Holdings {
+    fn clear_extra(&mut self) {
+        self.open.clear_extra();
+        self.high.clear_extra();
+        self.low.clear_extra();
+        self.close.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalPortfolioItem {
+    pub contract_decimals: i32,
+    pub contract_name: String,
+    pub contract_ticker_symbol: String,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: String,
+    pub holdings: Vec<Holdings>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalPortfolioItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = contract_name;
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = contract_ticker_symbol;
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = logo_url;
+        self
+    }
+
+    pub fn with_holdings(mut self, holdings: Vec<Holdings>) -> Self {
+        self.holdings = holdings;
+        self
+    }
+}
+
+impl Modelled for HistoricalPortfolioItem {
+    fn clear_extra(&mut self) {
+        self.holdings.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalPortfolio {
+    pub address: String,
+    pub updated_at: Timestamp,
+    pub next_update_at: Timestamp,
+    pub quote_currency: String,
+    pub chain_id: i64,
+    pub items: Vec<HistoricalPortfolioItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalPortfolio {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_next_update_at(mut self, next_update_at: Timestamp) -> Self {
+        self.next_update_at = next_update_at;
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = quote_currency;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<HistoricalPortfolioItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for HistoricalPortfolio {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalPortfolioData {
+    pub data: HistoricalPortfolio,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalPortfolioData {
+    pub fn with_data(mut self, data: HistoricalPortfolio) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for HistoricalPortfolioData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MethodCallsForTransfers {
+    pub sender_address: String,
+    pub method: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl MethodCallsForTransfers {
+    pub fn with_sender_address(mut self, sender_address: String) -> Self {
+        self.sender_address = sender_address;
+        self
+    }
+
+    pub fn with_method(mut self, method: String) -> Self {
+        self.method = method;
+        self
+    }
+}
+
+impl Modelled for MethodCallsForTransfers {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenTransferItem {
+    pub block_signed_at: Timestamp,
+    pub tx_hash: String,
+    pub from_address: String,
+    pub from_address_label: Option<String>,
+    pub to_address: String,
+    pub to_address_label: Option<String>,
+    pub contract_decimals: i32,
+    pub contract_name: String,
+    pub contract_ticker_symbol: String,
+    pub contract_address: String,
+    pub logo_url: String,
+    pub transfer_type: String,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub delta: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub balance: Option<U256>,
+    pub quote_rate: Option<f64>,
+    pub delta_quote: Option<f64>,
+    pub balance_quote: Option<f64>,
+    pub method_calls: Option<Vec<MethodCallsForTransfers>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TokenTransferItem {
+    pub fn with_block_signed_at(mut self, block_signed_at: Timestamp) -> Self {
+        self.block_signed_at = block_signed_at;
+        self
+    }
+
+    pub fn with_tx_hash(mut self, tx_hash: String) -> Self {
+        self.tx_hash = tx_hash;
+        self
+    }
+
+    pub fn with_from_address(mut self, from_address: String) -> Self {
+        self.from_address = from_address;
+        self
+    }
+
+    pub fn with_from_address_label(mut self, from_address_label: String) -> Self {
+        self.from_address_label = Some(from_address_label);
+        self
+    }
+
+    pub fn with_to_address(mut self, to_address: String) -> Self {
+        self.to_address = to_address;
+        self
+    }
+
+    pub fn with_to_address_label(mut self, to_address_label: String) -> Self {
+        self.to_address_label = Some(to_address_label);
+        self
+    }
+
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = contract_name;
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = contract_ticker_symbol;
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = logo_url;
+        self
+    }
+
+    pub fn with_transfer_type(mut self, transfer_type: String) -> Self {
+        self.transfer_type = transfer_type;
+        self
+    }
+
+    pub fn with_delta(mut self, delta: U256) -> Self {
+        self.delta = Some(delta);
+        self
+    }
+
+    pub fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+
+    pub fn with_delta_quote(mut self, delta_quote: f64) -> Self {
+        self.delta_quote = Some(delta_quote);
+        self
+    }
+
+    pub fn with_balance_quote(mut self, balance_quote: f64) -> Self {
+        self.balance_quote = Some(balance_quote);
+        self
+    }
+
+    pub fn with_method_calls(mut self, method_calls: Vec<MethodCallsForTransfers>) -> Self {
+        self.method_calls = Some(method_calls);
+        self
+    }
+}
+
+impl Modelled for TokenTransferItem {
+    fn clear_extra(&mut self) {
+        self.method_calls.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockTransactionWithContractTransfers {
+    #[serde(flatten)]
+    pub transaction: BaseTransaction,
+    pub transfers: Vec<TokenTransferItem>,
+}
+
+impl BlockTransactionWithContractTransfers {
+    pub fn with_transaction(mut self, transaction: BaseTransaction) -> Self {
+        self.transaction = transaction;
+        self
+    }
+
+    pub fn with_transfers(mut self, transfers: Vec<TokenTransferItem>) -> Self {
+        self.transfers = transfers;
+        self
+    }
+}
+
+impl Modelled for BlockTransactionWithContractTransfers {
+    fn clear_extra(&mut self) {
+        self.transaction.clear_extra();
+        self.transfers.clear_extra();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenTransfers {
+    pub address: String,
+    pub updated_at: Timestamp,
+    pub next_update_at: Timestamp,
+    pub quote_currency: String,
+    pub chain_id: i64,
+    pub items: Vec<BlockTransactionWithContractTransfers>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TokenTransfers {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_next_update_at(mut self, next_update_at: Timestamp) -> Self {
+        self.next_update_at = next_update_at;
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = quote_currency;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<BlockTransactionWithContractTransfers>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for TokenTransfers {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenTransfersData {
+    pub data: TokenTransfers,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TokenTransfersData {
+    pub fn with_data(mut self, data: TokenTransfers) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for TokenTransfersData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChangesInTokenHoldersItem {
+    pub token_holder: String,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub prev_balance: U256,
+    pub prev_block_height: u64,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub next_balance: U256,
+    pub next_block_height: u64,
+    #[serde(with = "amount::i256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub diff: I256,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ChangesInTokenHoldersItem {
+    pub fn with_token_holder(mut self, token_holder: String) -> Self {
+        self.token_holder = token_holder;
+        self
+    }
+
+    pub fn with_prev_balance(mut self, prev_balance: U256) -> Self {
+        self.prev_balance = prev_balance;
+        self
+    }
+
+    pub fn with_prev_block_height(mut self, prev_block_height: u64) -> Self {
+        self.prev_block_height = prev_block_height;
+        self
+    }
+
+    pub fn with_next_balance(mut self, next_balance: U256) -> Self {
+        self.next_balance = next_balance;
+        self
+    }
+
+    pub fn with_next_block_height(mut self, next_block_height: u64) -> Self {
+        self.next_block_height = next_block_height;
+        self
+    }
+
+    pub fn with_diff(mut self, diff: I256) -> Self {
+        self.diff = diff;
+        self
+    }
+}
+
+impl Modelled for ChangesInTokenHoldersItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChangesInTokenHolders {
+    pub updated_at: Timestamp,
+    pub items: Vec<ChangesInTokenHoldersItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ChangesInTokenHolders {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<ChangesInTokenHoldersItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for ChangesInTokenHolders {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChangesInTokenHoldersData {
+    pub data: ChangesInTokenHolders,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ChangesInTokenHoldersData {
+    pub fn with_data(mut self, data: ChangesInTokenHolders) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for ChangesInTokenHoldersData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockItem {
+    pub signed_at: Timestamp,
+    pub height: u64,
+    /// Only returned when getting a single block, not for block heights between dates
+    pub block_hash: Option<String>,
+    pub block_parent_hash: Option<String>,
+    pub extra_data: Option<String>,
+    pub miner_address: Option<String>,
+    pub mining_cost: Option<u64>,
+    pub gas_used: Option<u64>,
+    pub gas_limit: Option<u64>,
+    pub transactions_link: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BlockItem {
+    pub fn with_signed_at(mut self, signed_at: Timestamp) -> Self {
+        self.signed_at = signed_at;
+        self
+    }
+
+    pub fn with_height(mut self, height: u64) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_block_hash(mut self, block_hash: String) -> Self {
+        self.block_hash = Some(block_hash);
+        self
+    }
+
+    pub fn with_block_parent_hash(mut self, block_parent_hash: String) -> Self {
+        self.block_parent_hash = Some(block_parent_hash);
+        self
+    }
+
+    pub fn with_extra_data(mut self, extra_data: String) -> Self {
+        self.extra_data = Some(extra_data);
+        self
+    }
+
+    pub fn with_miner_address(mut self, miner_address: String) -> Self {
+        self.miner_address = Some(miner_address);
+        self
+    }
+
+    pub fn with_mining_cost(mut self, mining_cost: u64) -> Self {
+        self.mining_cost = Some(mining_cost);
+        self
+    }
+
+    pub fn with_gas_used(mut self, gas_used: u64) -> Self {
+        self.gas_used = Some(gas_used);
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    pub fn with_transactions_link(mut self, transactions_link: String) -> Self {
+        self.transactions_link = Some(transactions_link);
+        self
+    }
+}
+
+impl Modelled for BlockItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Block {
+    pub updated_at: Timestamp,
+    pub items: Vec<BlockItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Block {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<BlockItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for Block {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockData {
+    pub data: Block,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BlockData {
+    pub fn with_data(mut self, data: Block) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for BlockData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockTransactions {
+    pub updated_at: Timestamp,
+    pub chain_id: Option<i64>,
+    pub chain_name: Option<String>,
+    pub current_page: Option<u64>,
+    pub links: ApiLinks,
+    pub items: Vec<BlockTransactionWithLogEvents>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BlockTransactions {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_chain_name(mut self, chain_name: String) -> Self {
+        self.chain_name = Some(chain_name);
+        self
+    }
+
+    pub fn with_current_page(mut self, current_page: u64) -> Self {
+        self.current_page = Some(current_page);
+        self
+    }
+
+    pub fn with_links(mut self, links: ApiLinks) -> Self {
+        self.links = links;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<BlockTransactionWithLogEvents>) -> Self {
+        self.items = items;
+        self
+    }
+}
+
+impl Modelled for BlockTransactions {
+    fn clear_extra(&mut self) {
+        self.links.clear_extra();
+        self.items.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockTransactionsData {
+    pub data: BlockTransactions,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BlockTransactionsData {
+    pub fn with_data(mut self, data: BlockTransactions) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for BlockTransactionsData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionSummaryLink {
+    pub block_signed_at: Timestamp,
+    pub tx_hash: String,
+    pub tx_detail_link: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionSummaryLink {
+    pub fn with_block_signed_at(mut self, block_signed_at: Timestamp) -> Self {
+        self.block_signed_at = block_signed_at;
+        self
+    }
+
+    pub fn with_tx_hash(mut self, tx_hash: String) -> Self {
+        self.tx_hash = tx_hash;
+        self
+    }
+
+    pub fn with_tx_detail_link(mut self, tx_detail_link: String) -> Self {
+        self.tx_detail_link = Some(tx_detail_link);
+        self
+    }
+}
+
+impl Modelled for TransactionSummaryLink {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionSummaryItem {
+    pub total_count: u64,
+    pub transfer_count: Option<u64>,
+    pub earliest_transaction: Option<TransactionSummaryLink>,
+    pub latest_transaction: Option<TransactionSummaryLink>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionSummaryItem {
+    pub fn with_total_count(mut self, total_count: u64) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_transfer_count(mut self, transfer_count: u64) -> Self {
+        self.transfer_count = Some(transfer_count);
+        self
+    }
+
+    pub fn with_earliest_transaction(
+        mut self,
+        earliest_transaction: TransactionSummaryLink,
+    ) -> Self {
+        self.earliest_transaction = Some(earliest_transaction);
+        self
+    }
+
+    pub fn with_latest_transaction(mut self, latest_transaction: TransactionSummaryLink) -> Self {
+        self.latest_transaction = Some(latest_transaction);
+        self
+    }
+}
+
+impl Modelled for TransactionSummaryItem {
+    fn clear_extra(&mut self) {
+        self.earliest_transaction.clear_extra();
+        self.latest_transaction.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionSummary {
+    pub address: String,
+    pub updated_at: Timestamp,
+    pub chain_id: Option<i64>,
+    pub chain_name: Option<String>,
+    pub items: Vec<TransactionSummaryItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionSummary {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_chain_name(mut self, chain_name: String) -> Self {
+        self.chain_name = Some(chain_name);
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<TransactionSummaryItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for TransactionSummary {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransactionSummaryData {
+    pub data: TransactionSummary,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TransactionSummaryData {
+    pub fn with_data(mut self, data: TransactionSummary) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for TransactionSummaryData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LogEventsGeneric {
+    pub updated_at: Timestamp,
+    pub items: Vec<LogEventItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl LogEventsGeneric {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<LogEventItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for LogEventsGeneric {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LogEventsGenericData {
+    pub data: Option<LogEventsGeneric>,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl LogEventsGenericData {
+    pub fn with_data(mut self, data: LogEventsGeneric) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for LogEventsGenericData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ContractMetadataItem {
+    pub contract_decimals: i32,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ContractMetadataItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = logo_url;
+        self
+    }
+}
+
+impl Modelled for ContractMetadataItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ContractMetadata {
+    pub updated_at: Timestamp,
+    // the API returns items inside a doubled up list like [[ ... ]]
+    pub items: Vec<Vec<ContractMetadataItem>>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ContractMetadata {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<Vec<ContractMetadataItem>>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for ContractMetadata {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ContractMetadataData {
+    pub data: ContractMetadata,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ContractMetadataData {
+    pub fn with_data(mut self, data: ContractMetadata) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for ContractMetadataData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GenericChainInfoDisplay {
+    pub name: String,
+    pub chain_id: String,
+    pub is_testnet: bool,
+    pub db_schema_name: String,
+    pub label: String,
+    pub logo_url: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GenericChainInfoDisplay {
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: String) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_is_testnet(mut self, is_testnet: bool) -> Self {
+        self.is_testnet = is_testnet;
+        self
+    }
+
+    pub fn with_db_schema_name(mut self, db_schema_name: String) -> Self {
+        self.db_schema_name = db_schema_name;
+        self
+    }
+
+    pub fn with_label(mut self, label: String) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = logo_url;
+        self
+    }
+}
+
+impl Modelled for GenericChainInfoDisplay {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AllChain {
+    pub updated_at: Timestamp,
+    pub items: Vec<GenericChainInfoDisplay>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AllChain {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<GenericChainInfoDisplay>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for AllChain {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AllChainData {
+    pub data: AllChain,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AllChainData {
+    pub fn with_data(mut self, data: AllChain) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for AllChainData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GenericChainInfoStatusDisplay {
+    pub name: String,
+    pub chain_id: String,
+    pub is_testnet: bool,
+    pub logo_url: String,
+    pub synced_block_height: u64,
+    pub synced_blocked_signed_at: Timestamp,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GenericChainInfoStatusDisplay {
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: String) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_is_testnet(mut self, is_testnet: bool) -> Self {
+        self.is_testnet = is_testnet;
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = logo_url;
+        self
+    }
+
+    pub fn with_synced_block_height(mut self, synced_block_height: u64) -> Self {
+        self.synced_block_height = synced_block_height;
+        self
+    }
+
+    pub fn with_synced_blocked_signed_at(mut self, synced_blocked_signed_at: Timestamp) -> Self {
+        self.synced_blocked_signed_at = synced_blocked_signed_at;
+        self
+    }
+}
+
+impl Modelled for GenericChainInfoStatusDisplay {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AllChainStatuses {
+    pub updated_at: Timestamp,
+    pub items: Vec<GenericChainInfoStatusDisplay>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AllChainStatuses {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<GenericChainInfoStatusDisplay>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for AllChainStatuses {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AllChainStatusesData {
+    pub data: AllChainStatuses,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AllChainStatusesData {
+    pub fn with_data(mut self, data: AllChainStatuses) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for AllChainStatusesData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftTokenIdItem {
+    pub contract_decimals: Option<i32>,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: Option<String>,
+    #[serde(alias = "type")]
+    pub token_type: String,
+    #[serde(with = "amount::u256")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub token_id: U256,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftTokenIdItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = Some(contract_decimals);
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_token_type(mut self, token_type: String) -> Self {
+        self.token_type = token_type;
+        self
+    }
+
+    pub fn with_token_id(mut self, token_id: U256) -> Self {
+        self.token_id = token_id;
+        self
+    }
+}
+
+impl Modelled for NftTokenIdItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftTokenIds {
+    pub updated_at: Timestamp,
+    pub items: Vec<NftTokenIdItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftTokenIds {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<NftTokenIdItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for NftTokenIds {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftTokenIdsData {
+    pub data: NftTokenIds,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftTokenIdsData {
+    pub fn with_data(mut self, data: NftTokenIds) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for NftTokenIdsData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftTransactionsItem {
+    pub contract_decimals: Option<i32>,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: Option<String>,
+    #[serde(alias = "type")]
+    pub token_type: String,
+    pub nft_transactions: Vec<BlockTransactionWithLogEvents>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftTransactionsItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = Some(contract_decimals);
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_token_type(mut self, token_type: String) -> Self {
+        self.token_type = token_type;
+        self
+    }
+
+    pub fn with_nft_transactions(
+        mut self,
+        nft_transactions: Vec<BlockTransactionWithLogEvents>,
+    ) -> Self {
+        self.nft_transactions = nft_transactions;
+        self
+    }
+}
+
+impl Modelled for NftTransactionsItem {
+    fn clear_extra(&mut self) {
+        self.nft_transactions.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftTransactions {
+    pub updated_at: Timestamp,
+    pub items: Vec<NftTransactionsItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftTransactions {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<NftTransactionsItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for NftTransactions {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftTransactionsData {
+    pub data: NftTransactions,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftTransactionsData {
+    pub fn with_data(mut self, data: NftTransactions) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for NftTransactionsData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftMetadataItem {
+    pub contract_decimals: Option<i32>,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: Option<String>,
+    #[serde(alias = "type")]
+    pub token_type: String,
+    pub nft_data: Option<Vec<NftData>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftMetadataItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = Some(contract_decimals);
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_token_type(mut self, token_type: String) -> Self {
+        self.token_type = token_type;
+        self
+    }
+
+    pub fn with_nft_data(mut self, nft_data: Vec<NftData>) -> Self {
+        self.nft_data = Some(nft_data);
+        self
+    }
+}
+
+impl Modelled for NftMetadataItem {
+    fn clear_extra(&mut self) {
+        self.nft_data.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftMetadata {
+    pub updated_at: Timestamp,
+    pub items: Vec<NftMetadataItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftMetadata {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<NftMetadataItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for NftMetadata {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NftMetadataData {
+    pub data: NftMetadata,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NftMetadataData {
+    pub fn with_data(mut self, data: NftMetadata) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for NftMetadataData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PriceContractMetadata {
+    pub contract_decimals: Option<i32>,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: Option<String>,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl PriceContractMetadata {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = Some(contract_decimals);
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = Some(contract_address);
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+}
+
+impl Modelled for PriceContractMetadata {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PriceItem {
+    pub contract_metadata: Option<PriceContractMetadata>,
+    /// A calendar date in YYYY-MM-DD format rather than a timestamp
+    pub date: String,
+    pub price: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl PriceItem {
+    pub fn with_contract_metadata(mut self, contract_metadata: PriceContractMetadata) -> Self {
+        self.contract_metadata = Some(contract_metadata);
+        self
+    }
+
+    pub fn with_date(mut self, date: String) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+}
+
+impl Modelled for PriceItem {
+    fn clear_extra(&mut self) {
+        self.contract_metadata.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalPricesItem {
+    pub contract_decimals: Option<i32>,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: Option<String>,
+    pub update_at: Option<Timestamp>,
+    pub quote_currency: String,
+    pub prices: Vec<PriceItem>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalPricesItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = Some(contract_decimals);
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_update_at(mut self, update_at: Timestamp) -> Self {
+        self.update_at = Some(update_at);
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = quote_currency;
+        self
+    }
+
+    pub fn with_prices(mut self, prices: Vec<PriceItem>) -> Self {
+        self.prices = prices;
+        self
+    }
+}
+
+impl Modelled for HistoricalPricesItem {
+    fn clear_extra(&mut self) {
+        self.prices.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoricalPricesData {
+    /// One item for each contract address requested
+    pub data: Vec<HistoricalPricesItem>,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HistoricalPricesData {
+    pub fn with_data(mut self, data: Vec<HistoricalPricesItem>) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for HistoricalPricesData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpotPriceItem {
+    pub contract_decimals: Option<i32>,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: Option<String>,
+    pub supports_erc: Option<Vec<String>>,
+    pub logo_url: Option<String>,
+    pub quote_rate: Option<f64>,
+    pub rank: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SpotPriceItem {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = Some(contract_decimals);
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = Some(contract_address);
+        self
+    }
+
+    pub fn with_supports_erc(mut self, supports_erc: Vec<String>) -> Self {
+        self.supports_erc = Some(supports_erc);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+
+    pub fn with_rank(mut self, rank: i64) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+}
+
+impl Modelled for SpotPriceItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpotPrices {
+    pub updated_at: Timestamp,
+    pub items: Vec<SpotPriceItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SpotPrices {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<SpotPriceItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for SpotPrices {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpotPricesData {
+    pub data: SpotPrices,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SpotPricesData {
+    pub fn with_data(mut self, data: SpotPrices) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for SpotPricesData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykPoolToken {
+    pub contract_decimals: i32,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub logo_url: Option<String>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub reserve: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub volume_in_24h: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub volume_out_24h: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub volume_in_7d: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub volume_out_7d: Option<U256>,
+    pub quote_rate: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykPoolToken {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_reserve(mut self, reserve: U256) -> Self {
+        self.reserve = Some(reserve);
+        self
+    }
+
+    pub fn with_volume_in_24h(mut self, volume_in_24h: U256) -> Self {
+        self.volume_in_24h = Some(volume_in_24h);
+        self
+    }
+
+    pub fn with_volume_out_24h(mut self, volume_out_24h: U256) -> Self {
+        self.volume_out_24h = Some(volume_out_24h);
+        self
+    }
+
+    pub fn with_volume_in_7d(mut self, volume_in_7d: U256) -> Self {
+        self.volume_in_7d = Some(volume_in_7d);
+        self
+    }
+
+    pub fn with_volume_out_7d(mut self, volume_out_7d: U256) -> Self {
+        self.volume_out_7d = Some(volume_out_7d);
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+}
+
+impl Modelled for XykPoolToken {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykPoolItem {
+    pub dex_name: Option<String>,
+    pub exchange: String,
+    pub swap_count_24h: Option<u64>,
+    pub total_liquidity_quote: Option<f64>,
+    pub volume_24h_quote: Option<f64>,
+    pub fee_24h_quote: Option<f64>,
+    pub volume_7d_quote: Option<f64>,
+    pub fee_7d_quote: Option<f64>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub total_supply: Option<U256>,
+    pub quote_rate: Option<f64>,
+    pub annualized_fee: Option<f64>,
+    pub chain_id: Option<i64>,
+    pub chain_name: Option<String>,
+    pub block_height: Option<u64>,
+    pub token_0: XykPoolToken,
+    pub token_1: XykPoolToken,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykPoolItem {
+    pub fn with_dex_name(mut self, dex_name: String) -> Self {
+        self.dex_name = Some(dex_name);
+        self
+    }
+
+    pub fn with_exchange(mut self, exchange: String) -> Self {
+        self.exchange = exchange;
+        self
+    }
+
+    pub fn with_swap_count_24h(mut self, swap_count_24h: u64) -> Self {
+        self.swap_count_24h = Some(swap_count_24h);
+        self
+    }
+
+    pub fn with_total_liquidity_quote(mut self, total_liquidity_quote: f64) -> Self {
+        self.total_liquidity_quote = Some(total_liquidity_quote);
+        self
+    }
+
+    pub fn with_volume_24h_quote(mut self, volume_24h_quote: f64) -> Self {
+        self.volume_24h_quote = Some(volume_24h_quote);
+        self
+    }
+
+    pub fn with_fee_24h_quote(mut self, fee_24h_quote: f64) -> Self {
+        self.fee_24h_quote = Some(fee_24h_quote);
+        self
+    }
+
+    pub fn with_volume_7d_quote(mut self, volume_7d_quote: f64) -> Self {
+        self.volume_7d_quote = Some(volume_7d_quote);
+        self
+    }
+
+    pub fn with_fee_7d_quote(mut self, fee_7d_quote: f64) -> Self {
+        self.fee_7d_quote = Some(fee_7d_quote);
+        self
+    }
+
+    pub fn with_total_supply(mut self, total_supply: U256) -> Self {
+        self.total_supply = Some(total_supply);
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+
+    pub fn with_annualized_fee(mut self, annualized_fee: f64) -> Self {
+        self.annualized_fee = Some(annualized_fee);
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_chain_name(mut self, chain_name: String) -> Self {
+        self.chain_name = Some(chain_name);
+        self
+    }
+
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.block_height = Some(block_height);
+        self
+    }
+
+    pub fn with_token_0(mut self, token_0: XykPoolToken) -> Self {
+        self.token_0 = token_0;
+        self
+    }
+
+    pub fn with_token_1(mut self, token_1: XykPoolToken) -> Self {
+        self.token_1 = token_1;
+        self
+    }
+}
+
+impl Modelled for XykPoolItem {
+    fn clear_extra(&mut self) {
+        self.token_0.clear_extra();
+        self.token_1.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykPools {
+    pub updated_at: Timestamp,
+    pub items: Vec<XykPoolItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykPools {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<XykPoolItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for XykPools {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykPoolsData {
+    pub data: XykPools,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykPoolsData {
+    pub fn with_data(mut self, data: XykPools) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for XykPoolsData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykBalanceToken {
+    pub contract_decimals: i32,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub logo_url: Option<String>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub balance: Option<U256>,
+    pub quote: Option<f64>,
+    pub quote_rate: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykBalanceToken {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+
+    pub fn with_quote(mut self, quote: f64) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    pub fn with_quote_rate(mut self, quote_rate: f64) -> Self {
+        self.quote_rate = Some(quote_rate);
+        self
+    }
+}
+
+impl Modelled for XykBalanceToken {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykAddressBalanceItem {
+    /// The liquidity pool token held by the address
+    pub token: XykBalanceToken,
+    /// The share of the underlying pool tokens held through the liquidity pool token
+    pub token_0: XykBalanceToken,
+    pub token_1: XykBalanceToken,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykAddressBalanceItem {
+    pub fn with_token(mut self, token: XykBalanceToken) -> Self {
+        self.token = token;
+        self
+    }
+
+    pub fn with_token_0(mut self, token_0: XykBalanceToken) -> Self {
+        self.token_0 = token_0;
+        self
+    }
+
+    pub fn with_token_1(mut self, token_1: XykBalanceToken) -> Self {
+        self.token_1 = token_1;
+        self
+    }
+}
+
+impl Modelled for XykAddressBalanceItem {
+    fn clear_extra(&mut self) {
+        self.token.clear_extra();
+        self.token_0.clear_extra();
+        self.token_1.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykAddressBalances {
+    pub address: Option<String>,
+    pub updated_at: Timestamp,
+    pub items: Vec<XykAddressBalanceItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykAddressBalances {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<XykAddressBalanceItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for XykAddressBalances {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykAddressBalancesData {
+    pub data: XykAddressBalances,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykAddressBalancesData {
+    pub fn with_data(mut self, data: XykAddressBalances) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for XykAddressBalancesData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykTransactionToken {
+    pub contract_decimals: i32,
+    pub contract_name: Option<String>,
+    pub contract_ticker_symbol: Option<String>,
+    pub contract_address: String,
+    pub logo_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykTransactionToken {
+    pub fn with_contract_decimals(mut self, contract_decimals: i32) -> Self {
+        self.contract_decimals = contract_decimals;
+        self
+    }
+
+    pub fn with_contract_name(mut self, contract_name: String) -> Self {
+        self.contract_name = Some(contract_name);
+        self
+    }
+
+    pub fn with_contract_ticker_symbol(mut self, contract_ticker_symbol: String) -> Self {
+        self.contract_ticker_symbol = Some(contract_ticker_symbol);
+        self
+    }
+
+    pub fn with_contract_address(mut self, contract_address: String) -> Self {
+        self.contract_address = contract_address;
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+}
+
+impl Modelled for XykTransactionToken {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykTransactionItem {
+    pub block_signed_at: Timestamp,
+    pub tx_hash: String,
+    /// The kind of transaction e.g. SWAP, ADD_LIQUIDITY or REMOVE_LIQUIDITY
+    pub act: String,
+    pub address: String,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub amount0_in: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub amount0_out: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub amount1_in: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub amount1_out: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub amount0: Option<U256>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub amount1: Option<U256>,
+    pub to_address: Option<String>,
+    pub from_address: Option<String>,
+    pub sender_address: Option<String>,
+    pub total_quote: Option<f64>,
+    pub token_0_quote_rate: Option<f64>,
+    pub token_1_quote_rate: Option<f64>,
+    pub token_0: XykTransactionToken,
+    pub token_1: XykTransactionToken,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykTransactionItem {
+    pub fn with_block_signed_at(mut self, block_signed_at: Timestamp) -> Self {
+        self.block_signed_at = block_signed_at;
+        self
+    }
+
+    pub fn with_tx_hash(mut self, tx_hash: String) -> Self {
+        self.tx_hash = tx_hash;
+        self
+    }
+
+    pub fn with_act(mut self, act: String) -> Self {
+        self.act = act;
+        self
+    }
+
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_amount0_in(mut self, amount0_in: U256) -> Self {
+        self.amount0_in = Some(amount0_in);
+        self
+    }
+
+    pub fn with_amount0_out(mut self, amount0_out: U256) -> Self {
+        self.amount0_out = Some(amount0_out);
+        self
+    }
+
+    pub fn with_amount1_in(mut self, amount1_in: U256) -> Self {
+        self.amount1_in = Some(amount1_in);
+        self
+    }
+
+    pub fn with_amount1_out(mut self, amount1_out: U256) -> Self {
+        self.amount1_out = Some(amount1_out);
+        self
+    }
+
+    pub fn with_amount0(mut self, amount0: U256) -> Self {
+        self.amount0 = Some(amount0);
+        self
+    }
+
+    pub fn with_amount1(mut self, amount1: U256) -> Self {
+        self.amount1 = Some(amount1);
+        self
+    }
+
+    pub fn with_to_address(mut self, to_address: String) -> Self {
+        self.to_address = Some(to_address);
+        self
+    }
+
+    pub fn with_from_address(mut self, from_address: String) -> Self {
+        self.from_address = Some(from_address);
+        self
+    }
+
+    pub fn with_sender_address(mut self, sender_address: String) -> Self {
+        self.sender_address = Some(sender_address);
+        self
+    }
+
+    pub fn with_total_quote(mut self, total_quote: f64) -> Self {
+        self.total_quote = Some(total_quote);
+        self
+    }
+
+    pub fn with_token_0_quote_rate(mut self, token_0_quote_rate: f64) -> Self {
+        self.token_0_quote_rate = Some(token_0_quote_rate);
+        self
+    }
+
+    pub fn with_token_1_quote_rate(mut self, token_1_quote_rate: f64) -> Self {
+        self.token_1_quote_rate = Some(token_1_quote_rate);
+        self
+    }
+
+    pub fn with_token_0(mut self, token_0: XykTransactionToken) -> Self {
+        self.token_0 = token_0;
+        self
+    }
+
+    pub fn with_token_1(mut self, token_1: XykTransactionToken) -> Self {
+        self.token_1 = token_1;
+        self
+    }
+}
+
+impl Modelled for XykTransactionItem {
+    fn clear_extra(&mut self) {
+        self.token_0.clear_extra();
+        self.token_1.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykTransactions {
+    pub updated_at: Timestamp,
+    pub items: Vec<XykTransactionItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykTransactions {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<XykTransactionItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for XykTransactions {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykTransactionsData {
+    pub data: XykTransactions,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykTransactionsData {
+    pub fn with_data(mut self, data: XykTransactions) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for XykTransactionsData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykVolumeChartItem {
+    pub dex_name: Option<String>,
+    pub chain_id: Option<String>,
+    pub dt: Timestamp,
+    pub quote_currency: Option<String>,
+    pub swap_count_24: Option<u64>,
+    pub volume_quote: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykVolumeChartItem {
+    pub fn with_dex_name(mut self, dex_name: String) -> Self {
+        self.dex_name = Some(dex_name);
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: String) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_dt(mut self, dt: Timestamp) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = Some(quote_currency);
+        self
+    }
+
+    pub fn with_swap_count_24(mut self, swap_count_24: u64) -> Self {
+        self.swap_count_24 = Some(swap_count_24);
+        self
+    }
+
+    pub fn with_volume_quote(mut self, volume_quote: f64) -> Self {
+        self.volume_quote = Some(volume_quote);
+        self
+    }
+}
+
+impl Modelled for XykVolumeChartItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykLiquidityChartItem {
+    pub dex_name: Option<String>,
+    pub chain_id: Option<String>,
+    pub dt: Timestamp,
+    pub quote_currency: Option<String>,
+    pub liquidity_quote: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykLiquidityChartItem {
+    pub fn with_dex_name(mut self, dex_name: String) -> Self {
+        self.dex_name = Some(dex_name);
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: String) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_dt(mut self, dt: Timestamp) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = Some(quote_currency);
+        self
+    }
+
+    pub fn with_liquidity_quote(mut self, liquidity_quote: f64) -> Self {
+        self.liquidity_quote = Some(liquidity_quote);
+        self
+    }
+}
+
+impl Modelled for XykLiquidityChartItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykEcosystemItem {
+    pub dex_name: Option<String>,
+    pub chain_id: Option<String>,
+    pub quote_currency: Option<String>,
+    pub gas_token_price_quote: Option<f64>,
+    pub total_swaps_24h: Option<u64>,
+    pub total_active_pairs_7d: Option<u64>,
+    pub total_fees_24h: Option<f64>,
+    pub volume_chart_7d: Vec<XykVolumeChartItem>,
+    pub volume_chart_30d: Vec<XykVolumeChartItem>,
+    pub liquidity_chart_7d: Vec<XykLiquidityChartItem>,
+    pub liquidity_chart_30d: Vec<XykLiquidityChartItem>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykEcosystemItem {
+    pub fn with_dex_name(mut self, dex_name: String) -> Self {
+        self.dex_name = Some(dex_name);
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: String) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = Some(quote_currency);
+        self
+    }
+
+    pub fn with_gas_token_price_quote(mut self, gas_token_price_quote: f64) -> Self {
+        self.gas_token_price_quote = Some(gas_token_price_quote);
+        self
+    }
+
+    pub fn with_total_swaps_24h(mut self, total_swaps_24h: u64) -> Self {
+        self.total_swaps_24h = Some(total_swaps_24h);
+        self
+    }
+
+    pub fn with_total_active_pairs_7d(mut self, total_active_pairs_7d: u64) -> Self {
+        self.total_active_pairs_7d = Some(total_active_pairs_7d);
+        self
+    }
+
+    pub fn with_total_fees_24h(mut self, total_fees_24h: f64) -> Self {
+        self.total_fees_24h = Some(total_fees_24h);
+        self
+    }
+
+    pub fn with_volume_chart_7d(mut self, volume_chart_7d: Vec<XykVolumeChartItem>) -> Self {
+        self.volume_chart_7d = volume_chart_7d;
+        self
+    }
+
+    pub fn with_volume_chart_30d(mut self, volume_chart_30d: Vec<XykVolumeChartItem>) -> Self {
+        self.volume_chart_30d = volume_chart_30d;
+        self
+    }
+
+    pub fn with_liquidity_chart_7d(
+        mut self,
+        liquidity_chart_7d: Vec<XykLiquidityChartItem>,
+    ) -> Self {
+        self.liquidity_chart_7d = liquidity_chart_7d;
+        self
+    }
+
+    pub fn with_liquidity_chart_30d(
+        mut self,
+        liquidity_chart_30d: Vec<XykLiquidityChartItem>,
+    ) -> Self {
+        self.liquidity_chart_30d = liquidity_chart_30d;
+        self
+    }
+}
+
+impl Modelled for XykEcosystemItem {
+    fn clear_extra(&mut self) {
+        self.volume_chart_7d.clear_extra();
+        self.volume_chart_30d.clear_extra();
+        self.liquidity_chart_7d.clear_extra();
+        self.liquidity_chart_30d.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykEcosystem {
+    pub updated_at: Timestamp,
+    pub items: Vec<XykEcosystemItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykEcosystem {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<XykEcosystemItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for XykEcosystem {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct XykEcosystemData {
+    pub data: XykEcosystem,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl XykEcosystemData {
+    pub fn with_data(mut self, data: XykEcosystem) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for XykEcosystemData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GasPriceItem {
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub gas_price: Option<U256>,
+    pub gas_spent: Option<u64>,
+    pub gas_quote: Option<f64>,
+    pub other_fees: Option<f64>,
+    pub total_gas_quote: Option<f64>,
+    pub pretty_total_gas_quote: Option<String>,
+    /// The time window the gas price is estimated for e.g. "1 minute"
+    pub interval: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GasPriceItem {
+    pub fn with_gas_price(mut self, gas_price: U256) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    pub fn with_gas_spent(mut self, gas_spent: u64) -> Self {
+        self.gas_spent = Some(gas_spent);
+        self
+    }
+
+    pub fn with_gas_quote(mut self, gas_quote: f64) -> Self {
+        self.gas_quote = Some(gas_quote);
+        self
+    }
+
+    pub fn with_other_fees(mut self, other_fees: f64) -> Self {
+        self.other_fees = Some(other_fees);
+        self
+    }
+
+    pub fn with_total_gas_quote(mut self, total_gas_quote: f64) -> Self {
+        self.total_gas_quote = Some(total_gas_quote);
+        self
+    }
+
+    pub fn with_pretty_total_gas_quote(mut self, pretty_total_gas_quote: String) -> Self {
+        self.pretty_total_gas_quote = Some(pretty_total_gas_quote);
+        self
+    }
+
+    pub fn with_interval(mut self, interval: String) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+}
+
+impl Modelled for GasPriceItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GasPrices {
+    pub updated_at: Timestamp,
+    pub chain_id: Option<i64>,
+    pub chain_name: Option<String>,
+    pub quote_currency: Option<String>,
+    pub event_type: Option<String>,
+    pub gas_quote_rate: Option<f64>,
+    #[serde(with = "amount::u256::option", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub base_fee: Option<U256>,
+    pub items: Vec<GasPriceItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GasPrices {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_chain_name(mut self, chain_name: String) -> Self {
+        self.chain_name = Some(chain_name);
+        self
+    }
+
+    pub fn with_quote_currency(mut self, quote_currency: String) -> Self {
+        self.quote_currency = Some(quote_currency);
+        self
+    }
+
+    pub fn with_event_type(mut self, event_type: String) -> Self {
+        self.event_type = Some(event_type);
+        self
+    }
+
+    pub fn with_gas_quote_rate(mut self, gas_quote_rate: f64) -> Self {
+        self.gas_quote_rate = Some(gas_quote_rate);
+        self
+    }
+
+    pub fn with_base_fee(mut self, base_fee: U256) -> Self {
+        self.base_fee = Some(base_fee);
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<GasPriceItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for GasPrices {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GasPricesData {
+    pub data: GasPrices,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GasPricesData {
+    pub fn with_data(mut self, data: GasPrices) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for GasPricesData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResolvedAddressItem {
+    pub address: String,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ResolvedAddressItem {
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+}
+
+impl Modelled for ResolvedAddressItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResolvedAddress {
+    pub updated_at: Timestamp,
+    pub chain_id: Option<i64>,
+    pub chain_name: Option<String>,
+    pub items: Vec<ResolvedAddressItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ResolvedAddress {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: i64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_chain_name(mut self, chain_name: String) -> Self {
+        self.chain_name = Some(chain_name);
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<ResolvedAddressItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for ResolvedAddress {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResolvedAddressData {
+    pub data: ResolvedAddress,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ResolvedAddressData {
+    pub fn with_data(mut self, data: ResolvedAddress) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for ResolvedAddressData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AddressActivityItem {
+    pub name: String,
+    pub chain_id: String,
+    pub is_testnet: Option<bool>,
+    pub db_schema_name: Option<String>,
+    pub label: Option<String>,
+    pub category_label: Option<String>,
+    pub logo_url: Option<String>,
+    pub is_appchain: Option<bool>,
+    pub appchain_of: Option<Value>,
+    pub last_seen_at: Option<Timestamp>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AddressActivityItem {
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: String) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_is_testnet(mut self, is_testnet: bool) -> Self {
+        self.is_testnet = Some(is_testnet);
+        self
+    }
+
+    pub fn with_db_schema_name(mut self, db_schema_name: String) -> Self {
+        self.db_schema_name = Some(db_schema_name);
+        self
+    }
+
+    pub fn with_label(mut self, label: String) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn with_category_label(mut self, category_label: String) -> Self {
+        self.category_label = Some(category_label);
+        self
+    }
+
+    pub fn with_logo_url(mut self, logo_url: String) -> Self {
+        self.logo_url = Some(logo_url);
+        self
+    }
+
+    pub fn with_is_appchain(mut self, is_appchain: bool) -> Self {
+        self.is_appchain = Some(is_appchain);
+        self
+    }
+
+    pub fn with_appchain_of(mut self, appchain_of: Value) -> Self {
+        self.appchain_of = Some(appchain_of);
+        self
+    }
+
+    pub fn with_last_seen_at(mut self, last_seen_at: Timestamp) -> Self {
+        self.last_seen_at = Some(last_seen_at);
+        self
+    }
+}
+
+impl Modelled for AddressActivityItem {
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AddressActivity {
+    pub updated_at: Timestamp,
+    pub address: String,
+    pub items: Vec<AddressActivityItem>,
+    // pagination is left out of some responses
+    #[serde(flatten)]
+    pub pagination: Option<ApiPagination>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AddressActivity {
+    pub fn with_updated_at(mut self, updated_at: Timestamp) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_address(mut self, address: String) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<AddressActivityItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_pagination(mut self, pagination: ApiPagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+}
+
+impl Modelled for AddressActivity {
+    fn clear_extra(&mut self) {
+        self.items.clear_extra();
+        self.pagination.clear_extra();
+        self.extra.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AddressActivityData {
+    pub data: AddressActivity,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AddressActivityData {
+    pub fn with_data(mut self, data: AddressActivity) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for AddressActivityData {
+    fn clear_extra(&mut self) {
+        self.data.clear_extra();
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+
+/// The JSON Schema of a resource by its struct name e.g. `BalancesData`
+#[cfg(feature = "schemars")]
+pub fn json_schema(resource: &str) -> Option<schemars::schema::RootSchema> {
+    let schema = match resource {
+        "ApiError" => schemars::schema_for!(ApiError),
+        "ApiPagination" => schemars::schema_for!(ApiPagination),
+        "ApiLinks" => schemars::schema_for!(ApiLinks),
+        "WalletBalanceItem" => schemars::schema_for!(WalletBalanceItem),
+        "Balances" => schemars::schema_for!(Balances),
+        "BalancesData" => schemars::schema_for!(BalancesData),
+        "HistoricalBalanceItem" => schemars::schema_for!(HistoricalBalanceItem),
+        "HistoricalBalances" => schemars::schema_for!(HistoricalBalances),
+        "HistoricalBalancesData" => schemars::schema_for!(HistoricalBalancesData),
+        "NftAttribute" => schemars::schema_for!(NftAttribute),
+        "NftExternalData" => schemars::schema_for!(NftExternalData),
+        "NftData" => schemars::schema_for!(NftData),
+        "TokenHolderItem" => schemars::schema_for!(TokenHolderItem),
+        "TokenHolders" => schemars::schema_for!(TokenHolders),
+        "TokenHoldersData" => schemars::schema_for!(TokenHoldersData),
+        "LogDecodedParams" => schemars::schema_for!(LogDecodedParams),
+        "LogDecoded" => schemars::schema_for!(LogDecoded),
+        "LogEventItem" => schemars::schema_for!(LogEventItem),
+        "BaseTransaction" => schemars::schema_for!(BaseTransaction),
+        "BlockTransactionWithLogEvents" => schemars::schema_for!(BlockTransactionWithLogEvents),
+        "Transactions" => schemars::schema_for!(Transactions),
+        "TransactionsData" => schemars::schema_for!(TransactionsData),
+        "TransactionsV3" => schemars::schema_for!(TransactionsV3),
+        "TransactionsV3Data" => schemars::schema_for!(TransactionsV3Data),
+        "Transaction" => schemars::schema_for!(Transaction),
+        "TransactionData" => schemars::schema_for!(TransactionData),
+        "HoldingsPrice" => schemars::schema_for!(HoldingsPrice),
+        "Holdings" => schemars::schema_for!(Holdings),
+        "HistoricalPortfolioItem" => schemars::schema_for!(HistoricalPortfolioItem),
+        "HistoricalPortfolio" => schemars::schema_for!(HistoricalPortfolio),
+        "HistoricalPortfolioData" => schemars::schema_for!(HistoricalPortfolioData),
+        "MethodCallsForTransfers" => schemars::schema_for!(MethodCallsForTransfers),
+        "TokenTransferItem" => schemars::schema_for!(TokenTransferItem),
+        "BlockTransactionWithContractTransfers" => {
+            schemars::schema_for!(BlockTransactionWithContractTransfers)
+        }
+        "TokenTransfers" => schemars::schema_for!(TokenTransfers),
+        "TokenTransfersData" => schemars::schema_for!(TokenTransfersData),
+        "ChangesInTokenHoldersItem" => schemars::schema_for!(ChangesInTokenHoldersItem),
+        "ChangesInTokenHolders" => schemars::schema_for!(ChangesInTokenHolders),
+        "ChangesInTokenHoldersData" => schemars::schema_for!(ChangesInTokenHoldersData),
+        "BlockItem" => schemars::schema_for!(BlockItem),
+        "Block" => schemars::schema_for!(Block),
+        "BlockData" => schemars::schema_for!(BlockData),
+        "BlockTransactions" => schemars::schema_for!(BlockTransactions),
+        "BlockTransactionsData" => schemars::schema_for!(BlockTransactionsData),
+        "TransactionSummaryLink" => schemars::schema_for!(TransactionSummaryLink),
+        "TransactionSummaryItem" => schemars::schema_for!(TransactionSummaryItem),
+        "TransactionSummary" => schemars::schema_for!(TransactionSummary),
+        "TransactionSummaryData" => schemars::schema_for!(TransactionSummaryData),
+        "LogEventsGeneric" => schemars::schema_for!(LogEventsGeneric),
+        "LogEventsGenericData" => schemars::schema_for!(LogEventsGenericData),
+        "ContractMetadataItem" => schemars::schema_for!(ContractMetadataItem),
+        "ContractMetadata" => schemars::schema_for!(ContractMetadata),
+        "ContractMetadataData" => schemars::schema_for!(ContractMetadataData),
+        "GenericChainInfoDisplay" => schemars::schema_for!(GenericChainInfoDisplay),
+        "AllChain" => schemars::schema_for!(AllChain),
+        "AllChainData" => schemars::schema_for!(AllChainData),
+        "GenericChainInfoStatusDisplay" => schemars::schema_for!(GenericChainInfoStatusDisplay),
+        "AllChainStatuses" => schemars::schema_for!(AllChainStatuses),
+        "AllChainStatusesData" => schemars::schema_for!(AllChainStatusesData),
+        "NftTokenIdItem" => schemars::schema_for!(NftTokenIdItem),
+        "NftTokenIds" => schemars::schema_for!(NftTokenIds),
+        "NftTokenIdsData" => schemars::schema_for!(NftTokenIdsData),
+        "NftTransactionsItem" => schemars::schema_for!(NftTransactionsItem),
+        "NftTransactions" => schemars::schema_for!(NftTransactions),
+        "NftTransactionsData" => schemars::schema_for!(NftTransactionsData),
+        "NftMetadataItem" => schemars::schema_for!(NftMetadataItem),
+        "NftMetadata" => schemars::schema_for!(NftMetadata),
+        "NftMetadataData" => schemars::schema_for!(NftMetadataData),
+        "PriceContractMetadata" => schemars::schema_for!(PriceContractMetadata),
+        "PriceItem" => schemars::schema_for!(PriceItem),
+        "HistoricalPricesItem" => schemars::schema_for!(HistoricalPricesItem),
+        "HistoricalPricesData" => schemars::schema_for!(HistoricalPricesData),
+        "SpotPriceItem" => schemars::schema_for!(SpotPriceItem),
+        "SpotPrices" => schemars::schema_for!(SpotPrices),
+        "SpotPricesData" => schemars::schema_for!(SpotPricesData),
+        "XykPoolToken" => schemars::schema_for!(XykPoolToken),
+        "XykPoolItem" => schemars::schema_for!(XykPoolItem),
+        "XykPools" => schemars::schema_for!(XykPools),
+        "XykPoolsData" => schemars::schema_for!(XykPoolsData),
+        "XykBalanceToken" => schemars::schema_for!(XykBalanceToken),
+        "XykAddressBalanceItem" => schemars::schema_for!(XykAddressBalanceItem),
+        "XykAddressBalances" => schemars::schema_for!(XykAddressBalances),
+        "XykAddressBalancesData" => schemars::schema_for!(XykAddressBalancesData),
+        "XykTransactionToken" => schemars::schema_for!(XykTransactionToken),
+        "XykTransactionItem" => schemars::schema_for!(XykTransactionItem),
+        "XykTransactions" => schemars::schema_for!(XykTransactions),
+        "XykTransactionsData" => schemars::schema_for!(XykTransactionsData),
+        "XykVolumeChartItem" => schemars::schema_for!(XykVolumeChartItem),
+        "XykLiquidityChartItem" => schemars::schema_for!(XykLiquidityChartItem),
+        "XykEcosystemItem" => schemars::schema_for!(XykEcosystemItem),
+        "XykEcosystem" => schemars::schema_for!(XykEcosystem),
+        "XykEcosystemData" => schemars::schema_for!(XykEcosystemData),
+        "GasPriceItem" => schemars::schema_for!(GasPriceItem),
+        "GasPrices" => schemars::schema_for!(GasPrices),
+        "GasPricesData" => schemars::schema_for!(GasPricesData),
+        "ResolvedAddressItem" => schemars::schema_for!(ResolvedAddressItem),
+        "ResolvedAddress" => schemars::schema_for!(ResolvedAddress),
+        "ResolvedAddressData" => schemars::schema_for!(ResolvedAddressData),
+        "AddressActivityItem" => schemars::schema_for!(AddressActivityItem),
+        "AddressActivity" => schemars::schema_for!(AddressActivity),
+        "AddressActivityData" => schemars::schema_for!(AddressActivityData),
+        _ => return None,
+    };
+    Some(schema)
+}
@@ -0,0 +1,110 @@
+//! Builder for log event topic queries
+//!
+//! The Get Log Events by Topic Hash(es) endpoint takes the primary topics comma-separated in the
+//! URL path, with optional `secondary-topics` and `sender-address` query parameters. `TopicFilter`
+//! builds these, computing topic0 hashes from event signatures.
+//! ```
+//! use covalent_class_a::topics::TopicFilter;
+//!
+//! let filter = TopicFilter::new()
+//!     .event_signature("Transfer(address,address,uint256)")
+//!     .secondary_address("0xf4024faad5fafd0755875e3161524c9c4e1a1111")
+//!     .sender("0x5c74070fdea071359b86082bd9f9b3deaafbe32b");
+//! assert_eq!(
+//!     "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+//!     filter.path_topics()
+//! );
+//! ```
+use crate::abi::event_topic;
+use std::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TopicFilter {
+    pub primary_topics: Vec<String>,
+    pub secondary_topics: Vec<String>,
+    pub sender_addr: Option<String>,
+}
+
+impl TopicFilter {
+    pub fn new() -> TopicFilter {
+        TopicFilter::default()
+    }
+
+    /// Add a primary topic hash, e.g. a topic0 already hashed
+    pub fn topic(mut self, topic_hash: &str) -> TopicFilter {
+        self.primary_topics.push(topic_hash.to_lowercase());
+        self
+    }
+
+    /// Add a primary topic hashed from an event signature such as `Transfer(address,address,uint256)`
+    pub fn event_signature(mut self, signature: &str) -> TopicFilter {
+        self.primary_topics.push(event_topic(signature));
+        self
+    }
+
+    /// Add a secondary topic hash to match on the indexed params of an event
+    pub fn secondary_topic(mut self, topic_hash: &str) -> TopicFilter {
+        self.secondary_topics.push(topic_hash.to_lowercase());
+        self
+    }
+
+    /// Add an address as a secondary topic, left-padded to 32 bytes as it appears in topics
+    pub fn secondary_address(mut self, addr: &str) -> TopicFilter {
+        let addr = addr.trim_start_matches("0x").to_lowercase();
+        self.secondary_topics.push(format!("0x{:0>64}", addr));
+        self
+    }
+
+    /// Only match logs emitted by this contract address
+    pub fn sender(mut self, sender_addr: &str) -> TopicFilter {
+        self.sender_addr = Some(sender_addr.to_string());
+        self
+    }
+
+    /// The primary topics as they go in the URL path
+    pub fn path_topics(&self) -> String {
+        self.primary_topics.join(",")
+    }
+
+    /// The secondary topics and sender address as query parameters, each prefixed with `&`
+    pub fn query_params(&self) -> String {
+        let mut params = String::new();
+        if !self.secondary_topics.is_empty() {
+            params = format!(
+                "{}&secondary-topics={}",
+                params,
+                self.secondary_topics.join(",")
+            );
+        }
+        if let Some(sender_addr) = &self.sender_addr {
+            params = format!("{}&sender-address={}", params, sender_addr);
+        }
+        params
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.primary_topics.is_empty() {
+            return Err("A topic filter requires at least one primary topic".into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_filter_query() {
+        let filter = TopicFilter::new()
+            .topic("0xAB")
+            .topic("0xcd")
+            .secondary_address("0xF4024FAAD5FAFD0755875E3161524C9C4E1A1111");
+        assert_eq!("0xab,0xcd", filter.path_topics());
+        assert_eq!(
+            "&secondary-topics=0x000000000000000000000000f4024faad5fafd0755875e3161524c9c4e1a1111",
+            filter.query_params()
+        );
+        assert!(TopicFilter::new().validate().is_err());
+    }
+}
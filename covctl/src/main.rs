@@ -1,26 +1,42 @@
-use clap::{Parser, Subcommand};
-use covalent_class_a::CovalentClient;
+use clap::{Parser, Subcommand, ValueEnum};
+use covalent_class_a::blocks::parse_date_time;
+use covalent_class_a::drift::DriftMode;
+use covalent_class_a::holders::HoldersSnapshot;
+use covalent_class_a::primer::{Group, Match, Primer};
+use covalent_class_a::topics::TopicFilter;
+use covalent_class_a::{endpoints, resources, CovalentClient};
 use log::error;
-use serde_json::to_string_pretty;
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use serde_json::{json, to_string_pretty};
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Write};
 
 #[derive(Subcommand, Debug)]
 enum Action {
     /// Token balances for an address
-    TokenBalances(AddrPageFlag),
+    TokenBalances(TokenBalancesFlag),
     /// Historicial portfolio value for an address
     HistoricalPortfolioValue(AddrPageFlag),
     /// Token Transfers given an address and the contract address
     TokenTransfers(AddrPageTokenContractFlag),
     /// Token holders at any block height for an address
-    TokenHoldersAnyBh(AddrPageFlag),
+    TokenHoldersAnyBh(AddrBlockHeightPageFlag),
+    /// Every token holder at a block height as a single deduplicated list
+    HoldersSnapshot(HoldersSnapshotFlag),
     /// Changes in token holders between two block heights
     ChangesInTokenHolders(AddrPageBetweenBlocksFlag),
     /// Transactions for an address
     TransactionsForAddress(AddrPageFlag),
     /// Data on a single transaction given a transaction hash
     Transaction(TxHashPageFlag),
+    /// Transaction count and the earliest and latest transactions of an address
+    TransactionSummary(AddrPageFlag),
     /// Data on a block given a block height
     Block(BlockHeightPageFlag),
+    /// All transactions in a block given a block height
+    BlockTransactions(BlockHeightPageNumberFlag),
     /// Block heights given a start and end date
     BlockHeights(StartEndDatePageFlag),
     /// Log events by contract address within a start and end date
@@ -29,10 +45,593 @@ enum Action {
     LogEventsByTopicHashes(TopicSenderPageBetweenBlocksFlag),
     /// All contract metadata
     AllContractMetadata(Pagination),
+    /// NFT token IDs for an NFT contract address
+    NftTokenIds(ContractAddrPageFlag),
+    /// NFT transactions for a token ID of an NFT contract address
+    NftTransactions(ContractAddrTokenIdPageFlag),
+    /// NFT external metadata for a token ID of an NFT contract address
+    NftMetadata(ContractAddrTokenIdPageFlag),
+    /// Historical and spot token prices
+    #[clap(subcommand)]
+    Price(PriceAction),
+    /// Class B XY=K DEX pools, balances, transactions and charts
+    #[clap(subcommand)]
+    Xyk(XykAction),
+    /// Gas prices for an event type
+    GasPrices(EventTypeQuotePageFlag),
+    /// The address a name-service domain resolves to
+    ResolveAddress(NamePageFlag),
+    /// The chains an address has activity on
+    AddressActivity(AddrPageFlag),
     /// All chains
     AllChains(QuoteCurrencyPageFlag),
     /// All chain statuses
     AllChainStatuses(QuoteCurrencyPageFlag),
+    /// Run every endpoint against sample inputs and report fields which have drifted from the library structs
+    SchemaCheck,
+    /// The JSON Schema of the output of a command such as token-balances or xyk pools, or of a library struct such as BalancesData
+    Schema(SchemaFlag),
+}
+
+impl Action {
+    /// The address passed with --addr, which may be a name to resolve
+    fn addr_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Action::TokenBalances(flags) => Some(&mut flags.addr_page.addr),
+            Action::HistoricalPortfolioValue(flags)
+            | Action::TransactionsForAddress(flags)
+            | Action::TransactionSummary(flags)
+            | Action::AddressActivity(flags) => Some(&mut flags.addr),
+            Action::TokenTransfers(flags) => Some(&mut flags.addr_page.addr),
+            Action::TokenHoldersAnyBh(flags) => Some(&mut flags.addr_page.addr),
+            Action::HoldersSnapshot(flags) => Some(&mut flags.addr),
+            Action::ChangesInTokenHolders(flags) => Some(&mut flags.addr_page.addr),
+            Action::Xyk(XykAction::Balances(flags)) => Some(&mut flags.addr),
+            _ => None,
+        }
+    }
+}
+
+/// The endpoint path, extra query parameters and pagination of a --format server-csv or --group
+/// request
+type PathRequest<'a> = (String, Vec<(&'static str, String)>, &'a Pagination);
+
+impl Action {
+    /// The request of commands which can be fetched by path, with --format server-csv or --group,
+    /// resolving dates to block heights first. The commands which are not fetched by path relative
+    /// to the chain, or which make more than one request, have none
+    async fn path_request(
+        &self,
+        client: &CovalentClient,
+    ) -> Result<Option<PathRequest<'_>>, Box<dyn Error>> {
+        let request = match self {
+            Action::TokenBalances(flags) => match flags.resolve_at(client).await? {
+                Some(block_height) => (
+                    format!("address/{}/historical_balances/", flags.addr_page.addr),
+                    vec![("block-height", block_height)],
+                    &flags.addr_page.page,
+                ),
+                None => (
+                    format!("address/{}/balances_v2/", flags.addr_page.addr),
+                    if flags.nft {
+                        vec![
+                            ("nft", "true".to_string()),
+                            ("no-nft-fetch", "false".to_string()),
+                        ]
+                    } else {
+                        vec![]
+                    },
+                    &flags.addr_page.page,
+                ),
+            },
+            Action::HistoricalPortfolioValue(flags) => (
+                format!("address/{}/portfolio_v2/", flags.addr),
+                vec![],
+                &flags.page,
+            ),
+            Action::TokenTransfers(flags) => (
+                format!("address/{}/transfers_v2/", flags.addr_page.addr),
+                vec![("contract-address", flags.contract_addr.clone())],
+                &flags.addr_page.page,
+            ),
+            Action::TokenHoldersAnyBh(flags) => (
+                format!("tokens/{}/token_holders/", flags.addr_page.addr),
+                flags
+                    .block_height
+                    .iter()
+                    .map(|block_height| ("block-height", block_height.clone()))
+                    .collect(),
+                &flags.addr_page.page,
+            ),
+            Action::ChangesInTokenHolders(flags) => {
+                let (starting_block, ending_block) = flags.blocks.resolve(client).await?;
+                (
+                    format!("tokens/{}/token_holders_changes/", flags.addr_page.addr),
+                    vec![
+                        ("starting-block", starting_block),
+                        ("ending-block", ending_block),
+                    ],
+                    &flags.addr_page.page,
+                )
+            }
+            Action::TransactionsForAddress(flags) => (
+                format!("address/{}/transactions_v2/", flags.addr),
+                vec![],
+                &flags.page,
+            ),
+            Action::Transaction(flags) => (
+                format!("transaction_v2/{}/", flags.tx_hash),
+                vec![],
+                &flags.page,
+            ),
+            Action::TransactionSummary(flags) => (
+                format!("address/{}/transactions_summary/", flags.addr),
+                vec![],
+                &flags.page,
+            ),
+            Action::Block(flags) => (
+                format!("block_v2/{}/", flags.block_height),
+                vec![],
+                &flags.page,
+            ),
+            Action::BlockTransactions(flags) => (
+                match &flags.page_number {
+                    Some(page_number) => format!(
+                        "block/{}/transactions_v3/page/{}/",
+                        flags.block_height, page_number
+                    ),
+                    None => format!("block/{}/transactions_v3/", flags.block_height),
+                },
+                vec![],
+                // the page is part of the path
+                &NO_PAGINATION,
+            ),
+            Action::BlockHeights(flags) => (
+                format!("block_v2/{}/{}/", flags.start_date, flags.end_date),
+                vec![],
+                &flags.page,
+            ),
+            Action::LogEventsByContract(flags) => {
+                let (starting_block, ending_block) = flags.blocks.resolve(client).await?;
+                (
+                    format!("events/address/{}/", flags.contract_addr),
+                    vec![
+                        ("starting-block", starting_block),
+                        ("ending-block", ending_block),
+                    ],
+                    &flags.page,
+                )
+            }
+            Action::LogEventsByTopicHashes(flags) => {
+                let filter = flags.topic_filter();
+                let (starting_block, ending_block) = flags.blocks.resolve(client).await?;
+                let mut params = vec![
+                    ("starting-block", starting_block),
+                    ("ending-block", ending_block),
+                ];
+                if !filter.secondary_topics.is_empty() {
+                    params.push(("secondary-topics", filter.secondary_topics.join(",")));
+                }
+                if let Some(sender_addr) = &filter.sender_addr {
+                    params.push(("sender-address", sender_addr.clone()));
+                }
+                (
+                    format!("events/topics/{}/", filter.path_topics()),
+                    params,
+                    &flags.page,
+                )
+            }
+            Action::AllContractMetadata(flags) => {
+                ("tokens/tokenlists/all/".to_string(), vec![], flags)
+            }
+            Action::NftTokenIds(flags) => (
+                format!("tokens/{}/nft_token_ids/", flags.contract_addr),
+                vec![],
+                &flags.page,
+            ),
+            Action::NftTransactions(flags) => (
+                format!(
+                    "tokens/{}/nft_transactions/{}/",
+                    flags.contract_page.contract_addr, flags.token_id
+                ),
+                vec![],
+                &flags.contract_page.page,
+            ),
+            Action::NftMetadata(flags) => (
+                format!(
+                    "tokens/{}/nft_metadata/{}/",
+                    flags.contract_page.contract_addr, flags.token_id
+                ),
+                vec![],
+                &flags.contract_page.page,
+            ),
+            Action::Xyk(XykAction::Pools(flags)) => (
+                format!("xy=k/{}/pools/", flags.dex_name),
+                vec![],
+                &flags.page,
+            ),
+            Action::Xyk(XykAction::Pool(flags)) => (
+                format!(
+                    "xy=k/{}/pools/address/{}/",
+                    flags.dex_page.dex_name, flags.pool_addr
+                ),
+                vec![],
+                &flags.dex_page.page,
+            ),
+            Action::Xyk(XykAction::Balances(flags)) => (
+                format!(
+                    "xy=k/{}/address/{}/balances/",
+                    flags.dex_page.dex_name, flags.addr
+                ),
+                vec![],
+                &flags.dex_page.page,
+            ),
+            Action::Xyk(XykAction::Transactions(flags)) => (
+                format!(
+                    "xy=k/{}/pools/address/{}/transactions/",
+                    flags.dex_page.dex_name, flags.pool_addr
+                ),
+                vec![],
+                &flags.dex_page.page,
+            ),
+            Action::Xyk(XykAction::Ecosystem(flags)) => (
+                format!("xy=k/{}/ecosystem/", flags.dex_name),
+                vec![],
+                &flags.page,
+            ),
+            Action::GasPrices(flags) => (
+                format!("event/{}/gas_prices/", flags.event_type),
+                vec![("quote-currency", flags.quote_page.quote_currency.clone())],
+                &flags.quote_page.page,
+            ),
+            Action::ResolveAddress(flags) => (
+                format!("address/{}/resolve_address/", flags.name),
+                vec![],
+                &flags.page,
+            ),
+            // the pricing, address activity and chains endpoints are not under a chain
+            Action::Price(_)
+            | Action::AddressActivity(_)
+            | Action::AllChains(_)
+            | Action::AllChainStatuses(_)
+            | Action::HoldersSnapshot(_)
+            | Action::SchemaCheck
+            | Action::Schema(_) => return Ok(None),
+        };
+        Ok(Some(request))
+    }
+}
+
+/// Stream the CSV body of a command to stdout, or to a file if one is given
+async fn stream_server_csv(
+    client: &CovalentClient,
+    action: &Action,
+    output: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let (path, params, page) = action
+        .path_request(client)
+        .await?
+        .ok_or("--format server-csv is not supported for this command")?;
+    let params: Vec<(&str, &str)> = params
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .collect();
+    let mut resp = client
+        .get_csv_response(
+            &path,
+            &params,
+            page.page_size.clone(),
+            page.page_number.clone(),
+        )
+        .await?;
+    let mut writer: Box<dyn Write> = match output {
+        Some(output) => Box::new(File::create(output)?),
+        None => Box::new(io::stdout().lock()),
+    };
+    while let Some(chunk) = resp.chunk().await? {
+        writer.write_all(&chunk)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Print the groups of a --group request, which the typed commands cannot decode
+async fn output_grouped(client: &CovalentClient, action: &Action) -> Result<(), Box<dyn Error>> {
+    let (path, params, page) = action
+        .path_request(client)
+        .await?
+        .ok_or("--group is not supported for this command")?;
+    let params: Vec<(&str, &str)> = params
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .collect();
+    let grouped = client
+        .get_grouped(
+            &path,
+            &params,
+            page.page_size.clone(),
+            page.page_number.clone(),
+        )
+        .await?;
+    println!("{}", to_string_pretty(&grouped)?);
+    Ok(())
+}
+
+// sample inputs for schema-check, the same as the library tests use
+const SAMPLE_KLAYTN_ADDR: &str = "0xf4024faad5fafd0755875e3161524c9c4e1a1111";
+const SAMPLE_KLAYTN_TX_HASH: &str =
+    "0x269fad968de5baf8d324b64d0a19df72ccfc762b33e1760729633f4946e0c863";
+const SAMPLE_KLAYTN_CONTRACT_DAI: &str = "0x5c74070fdea071359b86082bd9f9b3deaafbe32b";
+const SAMPLE_KLAYTN_STARTING_BLOCK: &str = "91321199";
+const SAMPLE_KLAYTN_ENDING_BLOCK: &str = "91321208";
+const SAMPLE_START_DATE: &str = "2022-05-18";
+const SAMPLE_END_DATE: &str = "2022-05-20";
+const SAMPLE_TOPIC_HASH: &str =
+    "0x804c9b842b2748a22bb64b345453a3de7ca54a6ca45ce00d415894979e22897a";
+const SAMPLE_TOPIC_SENDER: &str = "0x7d2768dE32b0b80b7a3454c06BdAc94A69DDc7A9";
+const SAMPLE_NFT_CONTRACT: &str = "0xe4605d46fd0b3f8329d936a8b258d69276cba264";
+const SAMPLE_NFT_TOKEN_ID: &str = "123";
+const SAMPLE_DEX_NAME: &str = "uniswap_v2";
+const SAMPLE_DEX_POOL: &str = "0x21b8065d10f73ee2e260e5b47d3344d3ced7596e";
+const SAMPLE_DEX_ADDR: &str = "0x4121dd930b15742b6d2e89b41284a79320bb8503";
+const SAMPLE_NAME: &str = "demo.eth";
+
+// the struct each command prints, with an entry for each flag which changes what it prints
+const COMMAND_RESOURCES: &[(&str, &str)] = &[
+    ("token-balances", "BalancesData"),
+    ("token-balances --nft", "BalancesData"),
+    ("token-balances --at", "HistoricalBalancesData"),
+    ("historical-portfolio-value", "HistoricalPortfolioData"),
+    ("token-transfers", "TokenTransfersData"),
+    ("token-holders-any-bh", "TokenHoldersData"),
+    ("holders-snapshot", "HoldersSnapshot"),
+    ("changes-in-token-holders", "ChangesInTokenHoldersData"),
+    ("transactions-for-address", "TransactionsData"),
+    ("transaction", "TransactionData"),
+    ("transaction-summary", "TransactionSummaryData"),
+    ("block", "BlockData"),
+    ("block-transactions", "BlockTransactionsData"),
+    ("block-heights", "BlockData"),
+    ("log-events-by-contract", "LogEventsGenericData"),
+    ("log-events-by-topic-hashes", "LogEventsGenericData"),
+    ("all-contract-metadata", "ContractMetadataData"),
+    ("nft-token-ids", "NftTokenIdsData"),
+    ("nft-transactions", "NftTransactionsData"),
+    ("nft-metadata", "NftMetadataData"),
+    ("price historical", "HistoricalPricesData"),
+    ("price spot", "SpotPricesData"),
+    ("xyk pools", "XykPoolsData"),
+    ("xyk pool", "XykPoolsData"),
+    ("xyk balances", "XykAddressBalancesData"),
+    ("xyk transactions", "XykTransactionsData"),
+    ("xyk ecosystem", "XykEcosystemData"),
+    ("gas-prices", "GasPricesData"),
+    ("resolve-address", "ResolvedAddressData"),
+    ("address-activity", "AddressActivityData"),
+    ("all-chains", "AllChainData"),
+    ("all-chain-statuses", "AllChainStatusesData"),
+];
+
+/// The JSON Schema of the output of a command, a library struct or an endpoint
+fn output_schema(resource: &str) -> Option<RootSchema> {
+    let resource = COMMAND_RESOURCES
+        .iter()
+        .find(|(command, _)| *command == resource)
+        .map(|(_, resource)| *resource)
+        .or_else(|| endpoints::endpoint(resource).map(|endpoint| endpoint.resource))
+        .unwrap_or(resource);
+    match resource {
+        "HoldersSnapshot" => Some(schema_for!(HoldersSnapshot)),
+        _ => resources::json_schema(resource),
+    }
+}
+
+/// Run every endpoint against the sample inputs, on Klaytn or on the Ethereum Mainnet for the
+/// samples from the Covalent docs, returning the drift reports and the requests which failed
+async fn schema_check(client: &CovalentClient) -> serde_json::Value {
+    let mut klaytn = client.with_drift_check(DriftMode::Collect);
+    klaytn.chain_id = "8217".to_string();
+    // clones share the drift reports collected
+    let mut ethereum = klaytn.clone();
+    ethereum.chain_id = "1".to_string();
+    let page_size = || Some("10".to_string());
+
+    let mut failed = vec![];
+    macro_rules! sample {
+        ($endpoint:expr, $request:expr) => {
+            if let Err(e) = $request.await {
+                failed.push(json!({ "endpoint": $endpoint, "error": e.to_string() }));
+            }
+        };
+    }
+    sample!(
+        "token-balances",
+        klaytn.get_token_balances(SAMPLE_KLAYTN_ADDR, page_size(), None)
+    );
+    sample!(
+        "token-balances --nft",
+        klaytn.get_token_balances_with_nfts(SAMPLE_KLAYTN_ADDR, page_size(), None)
+    );
+    sample!(
+        "token-balances --at",
+        klaytn.get_historical_token_balances(
+            SAMPLE_KLAYTN_ADDR,
+            Some(SAMPLE_KLAYTN_ENDING_BLOCK),
+            None,
+            page_size(),
+            None
+        )
+    );
+    sample!(
+        "historical-portfolio-value",
+        klaytn.get_historical_portfolio_value(SAMPLE_KLAYTN_ADDR, page_size(), None)
+    );
+    sample!(
+        "token-transfers",
+        klaytn.get_token_transfers(
+            SAMPLE_KLAYTN_ADDR,
+            SAMPLE_KLAYTN_CONTRACT_DAI,
+            page_size(),
+            None
+        )
+    );
+    sample!(
+        "token-holders-any-bh",
+        klaytn.get_token_holders_any_bh(
+            SAMPLE_KLAYTN_CONTRACT_DAI,
+            Some(SAMPLE_KLAYTN_ENDING_BLOCK),
+            page_size(),
+            None
+        )
+    );
+    sample!(
+        "changes-in-token-holders",
+        klaytn.get_changes_in_token_holders(
+            SAMPLE_KLAYTN_CONTRACT_DAI,
+            SAMPLE_KLAYTN_STARTING_BLOCK,
+            SAMPLE_KLAYTN_ENDING_BLOCK,
+            page_size(),
+            None
+        )
+    );
+    sample!(
+        "transactions-for-address",
+        klaytn.get_transactions_for_address(SAMPLE_KLAYTN_ADDR, page_size(), None)
+    );
+    sample!(
+        "transactions-for-address v3",
+        klaytn.get_transactions_for_address_v3(SAMPLE_KLAYTN_ADDR, None)
+    );
+    sample!(
+        "transaction-summary",
+        klaytn.get_transaction_summary(SAMPLE_KLAYTN_ADDR, None, None)
+    );
+    sample!(
+        "transaction",
+        klaytn.get_transaction(SAMPLE_KLAYTN_TX_HASH, None, None)
+    );
+    sample!(
+        "block",
+        klaytn.get_a_block(SAMPLE_KLAYTN_STARTING_BLOCK, None, None)
+    );
+    sample!(
+        "block-transactions",
+        klaytn.get_block_transactions(SAMPLE_KLAYTN_STARTING_BLOCK, Some("0".to_string()))
+    );
+    sample!(
+        "block-heights",
+        klaytn.get_block_heights(SAMPLE_START_DATE, SAMPLE_END_DATE, page_size(), None)
+    );
+    sample!(
+        "log-events-by-contract",
+        klaytn.get_log_events_by_contract(
+            SAMPLE_KLAYTN_CONTRACT_DAI,
+            SAMPLE_KLAYTN_STARTING_BLOCK,
+            SAMPLE_KLAYTN_ENDING_BLOCK,
+            page_size(),
+            None
+        )
+    );
+    sample!(
+        "log-events-by-topic-hashes",
+        ethereum.get_log_events_by_topic_hashes(
+            SAMPLE_TOPIC_HASH,
+            SAMPLE_TOPIC_SENDER,
+            "12500000",
+            "12500100",
+            page_size(),
+            None
+        )
+    );
+    sample!(
+        "all-contract-metadata",
+        klaytn.get_all_contract_metadata(page_size(), None)
+    );
+    sample!(
+        "nft-token-ids",
+        ethereum.get_nft_token_ids(SAMPLE_NFT_CONTRACT, page_size(), None)
+    );
+    sample!(
+        "nft-transactions",
+        ethereum.get_nft_transactions(SAMPLE_NFT_CONTRACT, SAMPLE_NFT_TOKEN_ID, page_size(), None)
+    );
+    sample!(
+        "nft-metadata",
+        ethereum.get_nft_external_metadata(SAMPLE_NFT_CONTRACT, SAMPLE_NFT_TOKEN_ID, None, None)
+    );
+    sample!(
+        "price historical",
+        klaytn.get_historical_token_prices(
+            SAMPLE_KLAYTN_CONTRACT_DAI,
+            "USD",
+            Some(SAMPLE_START_DATE),
+            Some(SAMPLE_END_DATE),
+            None,
+            None
+        )
+    );
+    sample!("price spot", klaytn.get_spot_prices("KLAY,ETH", None, None));
+    sample!(
+        "xyk pools",
+        ethereum.get_xyk_pools(SAMPLE_DEX_NAME, page_size(), None)
+    );
+    sample!(
+        "xyk pool",
+        ethereum.get_xyk_pool_by_address(SAMPLE_DEX_NAME, SAMPLE_DEX_POOL, None, None)
+    );
+    sample!(
+        "xyk balances",
+        ethereum.get_xyk_address_exchange_balances(SAMPLE_DEX_NAME, SAMPLE_DEX_ADDR, None, None)
+    );
+    sample!(
+        "xyk transactions",
+        ethereum.get_xyk_pool_transactions(SAMPLE_DEX_NAME, SAMPLE_DEX_POOL, page_size(), None)
+    );
+    sample!(
+        "xyk ecosystem",
+        ethereum.get_xyk_ecosystem_chart(SAMPLE_DEX_NAME, None, None)
+    );
+    sample!(
+        "gas-prices",
+        klaytn.get_gas_prices("erc20", "USD", None, None)
+    );
+    sample!(
+        "resolve-address",
+        ethereum.get_resolved_address(SAMPLE_NAME, None, None)
+    );
+    sample!(
+        "address-activity",
+        klaytn.get_address_activity(SAMPLE_KLAYTN_ADDR, None, None)
+    );
+    sample!("all-chains", klaytn.get_all_chains("USD", None, None));
+    sample!(
+        "all-chain-statuses",
+        klaytn.get_all_chain_statuses("USD", None, None)
+    );
+
+    json!({ "drift": klaytn.take_drift_reports(), "failed": failed })
+}
+
+#[derive(Subcommand, Debug)]
+enum PriceAction {
+    /// Historical prices for contract addresses between two dates
+    Historical(HistoricalPriceFlag),
+    /// Spot prices for tickers
+    Spot(TickersPageFlag),
+}
+
+#[derive(Subcommand, Debug)]
+enum XykAction {
+    /// All pools of a DEX
+    Pools(DexPageFlag),
+    /// A pool of a DEX by its pool address
+    Pool(DexPoolPageFlag),
+    /// Liquidity pool token balances of an address on a DEX
+    Balances(DexAddrPageFlag),
+    /// Swap and liquidity transactions of a pool of a DEX
+    Transactions(DexPoolPageFlag),
+    /// Volume and liquidity charts of a DEX
+    Ecosystem(DexPageFlag),
 }
 
 #[derive(Parser, Debug)]
@@ -45,23 +644,139 @@ struct Pagination {
     page_number: Option<String>,
 }
 
+const NO_PAGINATION: Pagination = Pagination {
+    page_size: None,
+    page_number: None,
+};
+
 #[derive(Parser, Debug)]
 struct AddrPageFlag {
-    /// The wallet address
+    /// The wallet address, or a name-service domain such as demo.eth
     #[clap(long)]
     addr: String,
     #[clap(flatten)]
     page: Pagination,
 }
 
+#[derive(Parser, Debug)]
+struct AddrBlockHeightPageFlag {
+    /// The block height, the latest block is used if not set
+    #[clap(long)]
+    block_height: Option<String>,
+    #[clap(flatten)]
+    addr_page: AddrPageFlag,
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq)]
+enum OutputFormat {
+    /// JSON decoded and pretty printed by covctl
+    Json,
+    /// CSV written by covctl, for commands which build their own results such as holders-snapshot
+    Csv,
+    /// CSV returned by the API, streamed without decoding
+    ServerCsv,
+}
+
+#[derive(Parser, Debug)]
+struct HoldersSnapshotFlag {
+    /// The token address, or a name-service domain
+    #[clap(long)]
+    addr: String,
+    /// The block height, the latest block is used if not set
+    #[clap(long)]
+    block_height: Option<u64>,
+}
+
+#[derive(Parser, Debug)]
+struct TokenBalancesFlag {
+    #[clap(flatten)]
+    addr_page: AddrPageFlag,
+    /// Include NFTs and their metadata in the balances
+    #[clap(long)]
+    nft: bool,
+    /// Get the balances as they were at a block height, or at the first block signed at or after
+    /// a date in YYYY-MM-DD or RFC 3339 format
+    #[clap(long, conflicts_with = "nft")]
+    at: Option<String>,
+}
+
+impl TokenBalancesFlag {
+    /// Get the block height of --at, resolving it from a date if it is not a block height
+    async fn resolve_at(&self, client: &CovalentClient) -> Result<Option<String>, Box<dyn Error>> {
+        match &self.at {
+            Some(at) if at.parse::<u64>().is_ok() => Ok(Some(at.clone())),
+            Some(at) => {
+                let block_height = client
+                    .block_resolver()
+                    .block_at(parse_date_time(at)?)
+                    .await?;
+                Ok(Some(block_height.to_string()))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+struct ContractAddrPageFlag {
+    /// The contract or token address
+    #[clap(long)]
+    contract_addr: String,
+    #[clap(flatten)]
+    page: Pagination,
+}
+
+#[derive(Parser, Debug)]
+struct ContractAddrTokenIdPageFlag {
+    /// The NFT token ID
+    #[clap(long)]
+    token_id: String,
+    #[clap(flatten)]
+    contract_page: ContractAddrPageFlag,
+}
+
 #[derive(Parser, Debug)]
 struct StartEndBlockFlag {
     /// The starting block
-    #[clap(long)]
-    starting_block: String,
+    #[clap(
+        long,
+        required_unless_present = "start-date",
+        conflicts_with = "start-date"
+    )]
+    starting_block: Option<String>,
     /// The ending block
+    #[clap(
+        long,
+        required_unless_present = "end-date",
+        conflicts_with = "end-date"
+    )]
+    ending_block: Option<String>,
+    /// Start from the first block signed at or after this date, in YYYY-MM-DD or RFC 3339 format
     #[clap(long)]
-    ending_block: String,
+    start_date: Option<String>,
+    /// End at the first block signed at or after this date, in YYYY-MM-DD or RFC 3339 format
+    #[clap(long)]
+    end_date: Option<String>,
+}
+
+impl StartEndBlockFlag {
+    /// Get the starting and ending blocks, resolving them from dates if dates were passed
+    async fn resolve(&self, client: &CovalentClient) -> Result<(String, String), Box<dyn Error>> {
+        let resolver = client.block_resolver();
+        let starting_block = match (&self.starting_block, &self.start_date) {
+            (Some(block), _) => block.clone(),
+            (None, Some(date)) => resolver.block_at(parse_date_time(date)?).await?.to_string(),
+            (None, None) => {
+                return Err("Either --starting-block or --start-date is required".into())
+            }
+        };
+        let ending_block = match (&self.ending_block, &self.end_date) {
+            (Some(block), _) => block.clone(),
+            (None, Some(date)) => resolver.block_at(parse_date_time(date)?).await?.to_string(),
+            (None, None) => return Err("Either --ending-block or --end-date is required".into()),
+        };
+        Ok((starting_block, ending_block))
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -100,8 +815,6 @@ struct AddrPageBetweenBlocksFlag {
     addr_page: AddrPageFlag,
     #[clap(flatten)]
     blocks: StartEndBlockFlag,
-    #[clap(flatten)]
-    page: Pagination,
 }
 
 #[derive(Parser, Debug)]
@@ -113,6 +826,16 @@ struct BlockHeightPageFlag {
     page: Pagination,
 }
 
+#[derive(Parser, Debug)]
+struct BlockHeightPageNumberFlag {
+    /// The block height
+    #[clap(long)]
+    block_height: String,
+    /// The page of transactions, the most recent page if not set
+    #[clap(long)]
+    page_number: Option<String>,
+}
+
 #[derive(Parser, Debug)]
 struct ContractAddrPageBetweenBlocksFlag {
     #[clap(long)]
@@ -126,17 +849,112 @@ struct ContractAddrPageBetweenBlocksFlag {
 #[derive(Parser, Debug)]
 struct TopicSenderPageBetweenBlocksFlag {
     /// The topic hash - comma-separated to provide multiple
+    #[clap(long, required_unless_present = "event-signature")]
+    topic_hash: Option<String>,
+    /// An event signature to hash into a topic e.g. "Transfer(address,address,uint256)" - can be passed multiple times
+    #[clap(long)]
+    event_signature: Vec<String>,
+    /// Secondary topic hashes to match indexed params - comma-separated to provide multiple
     #[clap(long)]
-    topic_hash: String,
+    secondary_topics: Option<String>,
     /// The senders address
     #[clap(long)]
-    sender_addr: String,
+    sender_addr: Option<String>,
     #[clap(flatten)]
     blocks: StartEndBlockFlag,
     #[clap(flatten)]
     page: Pagination,
 }
 
+impl TopicSenderPageBetweenBlocksFlag {
+    /// The topic filter of the topic hashes, event signatures, secondary topics and sender
+    fn topic_filter(&self) -> TopicFilter {
+        let mut filter = TopicFilter::new();
+        for topic in self.topic_hash.iter().flat_map(|t| t.split(',')) {
+            filter = filter.topic(topic.trim());
+        }
+        for signature in &self.event_signature {
+            filter = filter.event_signature(signature);
+        }
+        for topic in self.secondary_topics.iter().flat_map(|t| t.split(',')) {
+            filter = filter.secondary_topic(topic.trim());
+        }
+        if let Some(sender_addr) = &self.sender_addr {
+            filter = filter.sender(sender_addr);
+        }
+        filter
+    }
+}
+
+#[derive(Parser, Debug)]
+struct DexPageFlag {
+    /// The DEX name e.g. uniswap_v2
+    #[clap(long)]
+    dex_name: String,
+    #[clap(flatten)]
+    page: Pagination,
+}
+
+#[derive(Parser, Debug)]
+struct DexPoolPageFlag {
+    /// The pool address
+    #[clap(long)]
+    pool_addr: String,
+    #[clap(flatten)]
+    dex_page: DexPageFlag,
+}
+
+#[derive(Parser, Debug)]
+struct DexAddrPageFlag {
+    /// The wallet address, or a name-service domain such as demo.eth
+    #[clap(long)]
+    addr: String,
+    #[clap(flatten)]
+    dex_page: DexPageFlag,
+}
+
+#[derive(Parser, Debug)]
+struct HistoricalPriceFlag {
+    /// The contract or token address - comma-separated to provide multiple
+    #[clap(long)]
+    contract_addr: String,
+    /// The first date of prices in YYYY-MM-DD format
+    #[clap(long)]
+    from: Option<String>,
+    /// The last date of prices in YYYY-MM-DD format
+    #[clap(long)]
+    to: Option<String>,
+    #[clap(flatten)]
+    quote_page: QuoteCurrencyPageFlag,
+}
+
+#[derive(Parser, Debug)]
+struct TickersPageFlag {
+    /// The token tickers e.g. KLAY - comma-separated to provide multiple
+    #[clap(long)]
+    tickers: String,
+    #[clap(flatten)]
+    page: Pagination,
+}
+
+#[derive(Parser, Debug)]
+struct EventTypeQuotePageFlag {
+    /// The event type to estimate gas for - erc20, nativetokens or uniswapv3
+    #[clap(long, default_value = "erc20")]
+    event_type: String,
+    #[clap(flatten)]
+    quote_page: QuoteCurrencyPageFlag,
+}
+
+#[derive(Parser, Debug)]
+struct NamePageFlag {
+    /// The name-service domain e.g. demo.eth
+    #[clap(long)]
+    name: String,
+    #[clap(flatten)]
+    page: Pagination,
+}
+
 #[derive(Parser, Debug)]
 struct QuoteCurrencyPageFlag {
     /// The quote currency format
@@ -146,6 +964,13 @@ struct QuoteCurrencyPageFlag {
     page: Pagination,
 }
 
+#[derive(Parser, Debug)]
+struct SchemaFlag {
+    /// The command, struct name or endpoint operation ID e.g. token-balances, token-balances --at, BalancesData or getTokenBalances
+    #[clap(required = true, allow_hyphen_values = true)]
+    resource: Vec<String>,
+}
+
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
@@ -157,17 +982,66 @@ struct Args {
     /// Your Covalent API key - if not set it will use environment variable COVALENT_API_KEY
     #[clap(short, long)]
     api_key: Option<String>,
+    /// The chain ID names passed to --addr are resolved on - the default is "1" for ENS on the Ethereum Mainnet
+    #[clap(long, default_value = "1")]
+    name_chain_id: String,
+    /// Only return items matching a Primer match expression in JSON e.g. '{"successful": true}'
+    #[clap(long = "match", global = true)]
+    match_expr: Option<String>,
+    /// Group items with a Primer group expression in JSON e.g. '{"_id": "to_address", "count": {"$count": {}}}'
+    #[clap(long, global = true)]
+    group: Option<String>,
+    /// The output format - server-csv streams the CSV returned by the API without decoding it, for
+    /// every command but holders-snapshot, price, address-activity, all-chains and all-chain-statuses
+    #[clap(long, value_enum, default_value = "json", global = true)]
+    format: OutputFormat,
+    /// Write --format server-csv output to this file instead of stdout
+    #[clap(long, global = true)]
+    output: Option<String>,
+}
+
+impl Args {
+    /// The Primer query built from --match and --group, if either was passed
+    fn primer(&self) -> Result<Option<Primer>, Box<dyn Error>> {
+        if self.match_expr.is_none() && self.group.is_none() {
+            return Ok(None);
+        }
+        let mut primer = Primer::new();
+        if let Some(match_expr) = &self.match_expr {
+            primer = primer.filter(Match::from_json(match_expr)?);
+        }
+        if let Some(group) = &self.group {
+            primer = primer.group(Group::from_json(group)?);
+        }
+        Ok(Some(primer))
+    }
 }
 
 #[tokio::main]
 async fn main() {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
-    let args = Args::parse();
+    let mut args = Args::parse();
+
+    // Schemas are known without a request so no API key is needed
+    if let Action::Schema(flags) = &args.action {
+        let resource = flags.resource.join(" ");
+        match output_schema(&resource) {
+            Some(schema) => match to_string_pretty(&schema) {
+                Ok(schema) => println!("{}", schema),
+                Err(e) => error!("Failed to format JSON Schema: {}", e),
+            },
+            None => error!(
+                "Unknown command or struct {}, pass a command such as token-balances or a struct such as BalancesData",
+                resource
+            ),
+        }
+        return;
+    }
 
     // If an API key is passed by CLI flag then create a client using that
     // Otherwise attempt to get the API key from an environment variable COVALENT_API_KEY
-    let client = match args.api_key {
-        Some(api_key) => match CovalentClient::new(&args.chain_id, &api_key) {
+    let client = match &args.api_key {
+        Some(api_key) => match CovalentClient::new(&args.chain_id, api_key) {
             Ok(client) => client,
             Err(e) => {
                 error!(
@@ -189,12 +1063,102 @@ async fn main() {
         },
     };
 
-    match args.action {
+    // Resolve a name passed to --addr such as demo.eth to its address
+    if let Some(addr) = args.action.addr_mut() {
+        let mut name_client = client.clone();
+        name_client.chain_id = args.name_chain_id.clone();
+        match name_client.resolve_name(addr).await {
+            Ok(resolved) => *addr = resolved,
+            Err(e) => {
+                error!("Failed to resolve {} to an address: {}", addr, e);
+                return;
+            }
+        }
+    }
+
+    // Filter and aggregate items on the server with Primer expressions from --match and --group
+    let client = match args.primer() {
+        Ok(Some(primer)) => client.with_primer(primer),
+        Ok(None) => client,
+        Err(e) => {
+            error!("Failed to parse Primer expression: {}", e);
+            return;
+        }
+    };
+
+    match args.format {
+        OutputFormat::ServerCsv => {
+            if let Err(e) = stream_server_csv(&client, &args.action, args.output.as_deref()).await {
+                error!("Failed to get server CSV: {}", e);
+            }
+            return;
+        }
+        OutputFormat::Csv if !matches!(args.action, Action::HoldersSnapshot(_)) => {
+            error!("--format csv is only supported by holders-snapshot, use --format server-csv");
+            return;
+        }
+        _ => (),
+    }
+
+    if args.group.is_some() {
+        if let Err(e) = output_grouped(&client, &args.action).await {
+            error!("Failed to get grouped items: {}", e);
+        }
+        return;
+    }
+
+    run(&client, args.action, args.format).await;
+}
+
+/// Run a command, printing what it returns
+async fn run(client: &CovalentClient, action: Action, format: OutputFormat) {
+    match action {
         Action::TokenBalances(flags) => {
-            match client
-                .get_token_balances(&flags.addr, flags.page.page_size, flags.page.page_number)
-                .await
-            {
+            let block_height = match flags.resolve_at(client).await {
+                Ok(block_height) => block_height,
+                Err(e) => {
+                    error!("Failed to resolve block height: {}", e);
+                    return;
+                }
+            };
+            let addr_page = flags.addr_page;
+            if let Some(block_height) = block_height {
+                match client
+                    .get_historical_token_balances(
+                        &addr_page.addr,
+                        Some(&block_height),
+                        None,
+                        addr_page.page.page_size,
+                        addr_page.page.page_number,
+                    )
+                    .await
+                {
+                    Ok(balances) => match to_string_pretty(&balances) {
+                        Ok(balances) => println!("{}", balances),
+                        Err(e) => error!("Failed to format historical balances: {}", e),
+                    },
+                    Err(e) => error!("Failed to get historical token balances: {}", e),
+                }
+                return;
+            }
+            let balances = if flags.nft {
+                client
+                    .get_token_balances_with_nfts(
+                        &addr_page.addr,
+                        addr_page.page.page_size,
+                        addr_page.page.page_number,
+                    )
+                    .await
+            } else {
+                client
+                    .get_token_balances(
+                        &addr_page.addr,
+                        addr_page.page.page_size,
+                        addr_page.page.page_number,
+                    )
+                    .await
+            };
+            match balances {
                 Ok(balances) => match to_string_pretty(&balances) {
                     Ok(balances) => println!("{}", balances),
                     Err(e) => error!("Failed to format balances: {}", e),
@@ -237,7 +1201,12 @@ async fn main() {
         }
         Action::TokenHoldersAnyBh(flags) => {
             match client
-                .get_token_holders_any_bh(&flags.addr, flags.page.page_size, flags.page.page_number)
+                .get_token_holders_any_bh(
+                    &flags.addr_page.addr,
+                    flags.block_height.as_deref(),
+                    flags.addr_page.page.page_size,
+                    flags.addr_page.page.page_number,
+                )
                 .await
             {
                 Ok(token_holders) => match to_string_pretty(&token_holders) {
@@ -247,12 +1216,37 @@ async fn main() {
                 Err(e) => error!("Failed to get token holders at any block height: {}", e),
             }
         }
+        Action::HoldersSnapshot(flags) => {
+            match client
+                .holders_snapshot(&flags.addr, flags.block_height)
+                .await
+            {
+                Ok(snapshot) => {
+                    let output = match format {
+                        OutputFormat::Csv => snapshot.to_csv(),
+                        _ => snapshot.to_json().map(|json| json + "\n"),
+                    };
+                    match output {
+                        Ok(output) => print!("{}", output),
+                        Err(e) => error!("Failed to format token holders snapshot: {}", e),
+                    }
+                }
+                Err(e) => error!("Failed to get token holders snapshot: {}", e),
+            }
+        }
         Action::ChangesInTokenHolders(flags) => {
+            let (starting_block, ending_block) = match flags.blocks.resolve(client).await {
+                Ok(blocks) => blocks,
+                Err(e) => {
+                    error!("Failed to resolve block range: {}", e);
+                    return;
+                }
+            };
             match client
                 .get_changes_in_token_holders(
                     &flags.addr_page.addr,
-                    &flags.blocks.starting_block,
-                    &flags.blocks.ending_block,
+                    &starting_block,
+                    &ending_block,
                     flags.addr_page.page.page_size,
                     flags.addr_page.page.page_number,
                 )
@@ -293,6 +1287,18 @@ async fn main() {
                 Err(e) => error!("Failed to get transaction data: {}", e),
             }
         }
+        Action::TransactionSummary(flags) => {
+            match client
+                .get_transaction_summary(&flags.addr, flags.page.page_size, flags.page.page_number)
+                .await
+            {
+                Ok(summary) => match to_string_pretty(&summary) {
+                    Ok(summary) => println!("{}", summary),
+                    Err(e) => error!("Failed to format transaction summary: {}", e),
+                },
+                Err(e) => error!("Failed to get transaction summary: {}", e),
+            }
+        }
         Action::Block(flags) => {
             match client
                 .get_a_block(
@@ -309,6 +1315,18 @@ async fn main() {
                 Err(e) => error!("Failed to get block data: {}", e),
             }
         }
+        Action::BlockTransactions(flags) => {
+            match client
+                .get_block_transactions(&flags.block_height, flags.page_number)
+                .await
+            {
+                Ok(transactions) => match to_string_pretty(&transactions) {
+                    Ok(transactions) => println!("{}", transactions),
+                    Err(e) => error!("Failed to format block transactions: {}", e),
+                },
+                Err(e) => error!("Failed to get block transactions: {}", e),
+            }
+        }
         Action::BlockHeights(flags) => {
             match client
                 .get_block_heights(
@@ -327,11 +1345,18 @@ async fn main() {
             }
         }
         Action::LogEventsByContract(flags) => {
+            let (starting_block, ending_block) = match flags.blocks.resolve(client).await {
+                Ok(blocks) => blocks,
+                Err(e) => {
+                    error!("Failed to resolve block range: {}", e);
+                    return;
+                }
+            };
             match client
                 .get_log_events_by_contract(
                     &flags.contract_addr,
-                    &flags.blocks.starting_block,
-                    &flags.blocks.ending_block,
+                    &starting_block,
+                    &ending_block,
                     flags.page.page_size,
                     flags.page.page_number,
                 )
@@ -345,12 +1370,19 @@ async fn main() {
             }
         }
         Action::LogEventsByTopicHashes(flags) => {
+            let filter = flags.topic_filter();
+            let (starting_block, ending_block) = match flags.blocks.resolve(client).await {
+                Ok(blocks) => blocks,
+                Err(e) => {
+                    error!("Failed to resolve block range: {}", e);
+                    return;
+                }
+            };
             match client
-                .get_log_events_by_topic_hashes(
-                    &flags.topic_hash,
-                    &flags.sender_addr,
-                    &flags.blocks.starting_block,
-                    &flags.blocks.ending_block,
+                .get_log_events_by_topic_filter(
+                    &filter,
+                    &starting_block,
+                    &ending_block,
                     flags.page.page_size,
                     flags.page.page_number,
                 )
@@ -375,6 +1407,211 @@ async fn main() {
                 Err(e) => error!("Failed to get log metadata: {}", e),
             }
         }
+        Action::NftTokenIds(flags) => {
+            match client
+                .get_nft_token_ids(
+                    &flags.contract_addr,
+                    flags.page.page_size,
+                    flags.page.page_number,
+                )
+                .await
+            {
+                Ok(token_ids) => match to_string_pretty(&token_ids) {
+                    Ok(token_ids) => println!("{}", token_ids),
+                    Err(e) => error!("Failed to format NFT token IDs: {}", e),
+                },
+                Err(e) => error!("Failed to get NFT token IDs: {}", e),
+            }
+        }
+        Action::NftTransactions(flags) => {
+            match client
+                .get_nft_transactions(
+                    &flags.contract_page.contract_addr,
+                    &flags.token_id,
+                    flags.contract_page.page.page_size,
+                    flags.contract_page.page.page_number,
+                )
+                .await
+            {
+                Ok(transactions) => match to_string_pretty(&transactions) {
+                    Ok(transactions) => println!("{}", transactions),
+                    Err(e) => error!("Failed to format NFT transactions: {}", e),
+                },
+                Err(e) => error!("Failed to get NFT transactions: {}", e),
+            }
+        }
+        Action::NftMetadata(flags) => {
+            match client
+                .get_nft_external_metadata(
+                    &flags.contract_page.contract_addr,
+                    &flags.token_id,
+                    flags.contract_page.page.page_size,
+                    flags.contract_page.page.page_number,
+                )
+                .await
+            {
+                Ok(metadata) => match to_string_pretty(&metadata) {
+                    Ok(metadata) => println!("{}", metadata),
+                    Err(e) => error!("Failed to format NFT metadata: {}", e),
+                },
+                Err(e) => error!("Failed to get NFT metadata: {}", e),
+            }
+        }
+        Action::Price(PriceAction::Historical(flags)) => {
+            match client
+                .get_historical_token_prices(
+                    &flags.contract_addr,
+                    &flags.quote_page.quote_currency,
+                    flags.from.as_deref(),
+                    flags.to.as_deref(),
+                    flags.quote_page.page.page_size,
+                    flags.quote_page.page.page_number,
+                )
+                .await
+            {
+                Ok(prices) => match to_string_pretty(&prices) {
+                    Ok(prices) => println!("{}", prices),
+                    Err(e) => error!("Failed to format historical prices: {}", e),
+                },
+                Err(e) => error!("Failed to get historical prices: {}", e),
+            }
+        }
+        Action::Price(PriceAction::Spot(flags)) => {
+            match client
+                .get_spot_prices(&flags.tickers, flags.page.page_size, flags.page.page_number)
+                .await
+            {
+                Ok(prices) => match to_string_pretty(&prices) {
+                    Ok(prices) => println!("{}", prices),
+                    Err(e) => error!("Failed to format spot prices: {}", e),
+                },
+                Err(e) => error!("Failed to get spot prices: {}", e),
+            }
+        }
+        Action::Xyk(XykAction::Pools(flags)) => {
+            match client
+                .get_xyk_pools(
+                    &flags.dex_name,
+                    flags.page.page_size,
+                    flags.page.page_number,
+                )
+                .await
+            {
+                Ok(pools) => match to_string_pretty(&pools) {
+                    Ok(pools) => println!("{}", pools),
+                    Err(e) => error!("Failed to format XY=K pools: {}", e),
+                },
+                Err(e) => error!("Failed to get XY=K pools: {}", e),
+            }
+        }
+        Action::Xyk(XykAction::Pool(flags)) => {
+            match client
+                .get_xyk_pool_by_address(
+                    &flags.dex_page.dex_name,
+                    &flags.pool_addr,
+                    flags.dex_page.page.page_size,
+                    flags.dex_page.page.page_number,
+                )
+                .await
+            {
+                Ok(pool) => match to_string_pretty(&pool) {
+                    Ok(pool) => println!("{}", pool),
+                    Err(e) => error!("Failed to format XY=K pool: {}", e),
+                },
+                Err(e) => error!("Failed to get XY=K pool: {}", e),
+            }
+        }
+        Action::Xyk(XykAction::Balances(flags)) => {
+            match client
+                .get_xyk_address_exchange_balances(
+                    &flags.dex_page.dex_name,
+                    &flags.addr,
+                    flags.dex_page.page.page_size,
+                    flags.dex_page.page.page_number,
+                )
+                .await
+            {
+                Ok(balances) => match to_string_pretty(&balances) {
+                    Ok(balances) => println!("{}", balances),
+                    Err(e) => error!("Failed to format XY=K address balances: {}", e),
+                },
+                Err(e) => error!("Failed to get XY=K address balances: {}", e),
+            }
+        }
+        Action::Xyk(XykAction::Transactions(flags)) => {
+            match client
+                .get_xyk_pool_transactions(
+                    &flags.dex_page.dex_name,
+                    &flags.pool_addr,
+                    flags.dex_page.page.page_size,
+                    flags.dex_page.page.page_number,
+                )
+                .await
+            {
+                Ok(transactions) => match to_string_pretty(&transactions) {
+                    Ok(transactions) => println!("{}", transactions),
+                    Err(e) => error!("Failed to format XY=K pool transactions: {}", e),
+                },
+                Err(e) => error!("Failed to get XY=K pool transactions: {}", e),
+            }
+        }
+        Action::Xyk(XykAction::Ecosystem(flags)) => {
+            match client
+                .get_xyk_ecosystem_chart(
+                    &flags.dex_name,
+                    flags.page.page_size,
+                    flags.page.page_number,
+                )
+                .await
+            {
+                Ok(ecosystem) => match to_string_pretty(&ecosystem) {
+                    Ok(ecosystem) => println!("{}", ecosystem),
+                    Err(e) => error!("Failed to format XY=K ecosystem chart: {}", e),
+                },
+                Err(e) => error!("Failed to get XY=K ecosystem chart: {}", e),
+            }
+        }
+        Action::GasPrices(flags) => {
+            match client
+                .get_gas_prices(
+                    &flags.event_type,
+                    &flags.quote_page.quote_currency,
+                    flags.quote_page.page.page_size,
+                    flags.quote_page.page.page_number,
+                )
+                .await
+            {
+                Ok(gas_prices) => match to_string_pretty(&gas_prices) {
+                    Ok(gas_prices) => println!("{}", gas_prices),
+                    Err(e) => error!("Failed to format gas prices: {}", e),
+                },
+                Err(e) => error!("Failed to get gas prices: {}", e),
+            }
+        }
+        Action::ResolveAddress(flags) => {
+            match client
+                .get_resolved_address(&flags.name, flags.page.page_size, flags.page.page_number)
+                .await
+            {
+                Ok(resolved) => match to_string_pretty(&resolved) {
+                    Ok(resolved) => println!("{}", resolved),
+                    Err(e) => error!("Failed to format resolved address: {}", e),
+                },
+                Err(e) => error!("Failed to get resolved address: {}", e),
+            }
+        }
+        Action::AddressActivity(flags) => {
+            match client
+                .get_address_activity(&flags.addr, flags.page.page_size, flags.page.page_number)
+                .await
+            {
+                Ok(activity) => match to_string_pretty(&activity) {
+                    Ok(activity) => println!("{}", activity),
+                    Err(e) => error!("Failed to format address activity: {}", e),
+                },
+                Err(e) => error!("Failed to get address activity: {}", e),
+            }
+        }
         Action::AllChains(flags) => {
             match client
                 .get_all_chains(
@@ -393,7 +1630,7 @@ async fn main() {
         }
         Action::AllChainStatuses(flags) => {
             match client
-                .get_all_chains(
+                .get_all_chain_statuses(
                     &flags.quote_currency,
                     flags.page.page_size,
                     flags.page.page_number,
@@ -407,5 +1644,297 @@ async fn main() {
                 Err(e) => error!("Failed to get chain statuses data: {}", e),
             }
         }
+        Action::SchemaCheck => match to_string_pretty(&schema_check(client).await) {
+            Ok(report) => println!("{}", report),
+            Err(e) => error!("Failed to format schema drift report: {}", e),
+        },
+        // printed before the client is created
+        Action::Schema(_) => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    // the arguments each command of COMMAND_RESOURCES is run with
+    const COMMAND_ARGS: &[(&str, &[&str])] = &[
+        ("token-balances", &["--addr", SAMPLE_KLAYTN_ADDR]),
+        ("token-balances --nft", &["--addr", SAMPLE_KLAYTN_ADDR]),
+        (
+            "token-balances --at",
+            // the block height is the value of --at
+            &[SAMPLE_KLAYTN_ENDING_BLOCK, "--addr", SAMPLE_KLAYTN_ADDR],
+        ),
+        (
+            "historical-portfolio-value",
+            &["--addr", SAMPLE_KLAYTN_ADDR],
+        ),
+        (
+            "token-transfers",
+            &[
+                "--addr",
+                SAMPLE_KLAYTN_ADDR,
+                "--contract-addr",
+                SAMPLE_KLAYTN_CONTRACT_DAI,
+            ],
+        ),
+        (
+            "token-holders-any-bh",
+            &["--addr", SAMPLE_KLAYTN_CONTRACT_DAI],
+        ),
+        ("holders-snapshot", &["--addr", SAMPLE_KLAYTN_CONTRACT_DAI]),
+        (
+            "changes-in-token-holders",
+            &[
+                "--addr",
+                SAMPLE_KLAYTN_CONTRACT_DAI,
+                "--starting-block",
+                SAMPLE_KLAYTN_STARTING_BLOCK,
+                "--ending-block",
+                SAMPLE_KLAYTN_ENDING_BLOCK,
+            ],
+        ),
+        ("transactions-for-address", &["--addr", SAMPLE_KLAYTN_ADDR]),
+        ("transaction", &["--tx-hash", SAMPLE_KLAYTN_TX_HASH]),
+        ("transaction-summary", &["--addr", SAMPLE_KLAYTN_ADDR]),
+        ("block", &["--block-height", SAMPLE_KLAYTN_STARTING_BLOCK]),
+        (
+            "block-transactions",
+            &["--block-height", SAMPLE_KLAYTN_STARTING_BLOCK],
+        ),
+        (
+            "block-heights",
+            &[
+                "--start-date",
+                SAMPLE_START_DATE,
+                "--end-date",
+                SAMPLE_END_DATE,
+            ],
+        ),
+        (
+            "log-events-by-contract",
+            &[
+                "--contract-addr",
+                SAMPLE_KLAYTN_CONTRACT_DAI,
+                "--starting-block",
+                SAMPLE_KLAYTN_STARTING_BLOCK,
+                "--ending-block",
+                SAMPLE_KLAYTN_ENDING_BLOCK,
+            ],
+        ),
+        (
+            "log-events-by-topic-hashes",
+            &[
+                "--topic-hash",
+                SAMPLE_TOPIC_HASH,
+                "--starting-block",
+                "12500000",
+                "--ending-block",
+                "12500100",
+            ],
+        ),
+        ("all-contract-metadata", &[]),
+        ("nft-token-ids", &["--contract-addr", SAMPLE_NFT_CONTRACT]),
+        (
+            "nft-transactions",
+            &[
+                "--contract-addr",
+                SAMPLE_NFT_CONTRACT,
+                "--token-id",
+                SAMPLE_NFT_TOKEN_ID,
+            ],
+        ),
+        (
+            "nft-metadata",
+            &[
+                "--contract-addr",
+                SAMPLE_NFT_CONTRACT,
+                "--token-id",
+                SAMPLE_NFT_TOKEN_ID,
+            ],
+        ),
+        (
+            "price historical",
+            &["--contract-addr", SAMPLE_KLAYTN_CONTRACT_DAI],
+        ),
+        ("price spot", &["--tickers", "KLAY"]),
+        ("xyk pools", &["--dex-name", SAMPLE_DEX_NAME]),
+        (
+            "xyk pool",
+            &[
+                "--dex-name",
+                SAMPLE_DEX_NAME,
+                "--pool-addr",
+                SAMPLE_DEX_POOL,
+            ],
+        ),
+        (
+            "xyk balances",
+            &["--dex-name", SAMPLE_DEX_NAME, "--addr", SAMPLE_DEX_ADDR],
+        ),
+        (
+            "xyk transactions",
+            &[
+                "--dex-name",
+                SAMPLE_DEX_NAME,
+                "--pool-addr",
+                SAMPLE_DEX_POOL,
+            ],
+        ),
+        ("xyk ecosystem", &["--dex-name", SAMPLE_DEX_NAME]),
+        ("gas-prices", &[]),
+        ("resolve-address", &["--name", SAMPLE_NAME]),
+        ("address-activity", &["--addr", SAMPLE_KLAYTN_ADDR]),
+        ("all-chains", &[]),
+        ("all-chain-statuses", &[]),
+    ];
+
+    /// Serve an empty JSON object to every request, returning the base URL and the targets of the
+    /// requests served
+    async fn serve_empty_object() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let targets = Arc::new(Mutex::new(vec![]));
+        let served = targets.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = vec![];
+                let mut buf = [0; 1024];
+                while !request.windows(4).any(|end| end == b"\r\n\r\n") {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                // the request line is e.g. GET /v1/8217/block_v2/91321199/?key=ckey_test HTTP/1.1
+                if let Some(target) = String::from_utf8_lossy(&request).split(' ').nth(1) {
+                    served.lock().unwrap().push(target.to_string());
+                }
+                let _ = stream
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                          content-length: 2\r\nconnection: close\r\n\r\n{}",
+                    )
+                    .await;
+            }
+        });
+        (base_url, targets)
+    }
+
+    /// The path of a request target and its query parameters in order, without the format
+    fn path_and_params(target: &str) -> (String, Vec<String>) {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let mut params: Vec<String> = query
+            .split('&')
+            .filter(|param| !param.is_empty() && !param.starts_with("format="))
+            .map(str::to_string)
+            .collect();
+        params.sort();
+        (path.to_string(), params)
+    }
+
+    #[tokio::test]
+    async fn test_path_requests() {
+        let (base_url, targets) = serve_empty_object().await;
+        let mut client = CovalentClient::new("8217", "ckey_test").unwrap();
+        client.base_url = base_url;
+        let output = std::env::temp_dir().join("covctl-test-path-requests.csv");
+        let mut unsupported = vec![];
+        for (command, command_args) in COMMAND_ARGS {
+            let args = || {
+                Args::try_parse_from(
+                    ["covctl"]
+                        .into_iter()
+                        .chain(command.split(' '))
+                        .chain(command_args.iter().copied()),
+                )
+                .unwrap()
+            };
+            if args().action.path_request(&client).await.unwrap().is_none() {
+                unsupported.push(*command);
+                continue;
+            }
+            run(&client, args().action, OutputFormat::Json).await;
+            stream_server_csv(&client, &args().action, output.to_str())
+                .await
+                .unwrap();
+
+            // the server CSV is fetched from the same endpoint as the command decodes
+            let served = std::mem::take(&mut *targets.lock().unwrap());
+            assert_eq!(2, served.len(), "{} made {:?}", command, served);
+            assert!(served[1].contains("format=csv"));
+            assert_eq!(
+                path_and_params(&served[0]),
+                path_and_params(&served[1]),
+                "{}",
+                command
+            );
+        }
+        let _ = std::fs::remove_file(output);
+        // the commands listed in the help of --format
+        assert_eq!(
+            vec![
+                "holders-snapshot",
+                "price historical",
+                "price spot",
+                "address-activity",
+                "all-chains",
+                "all-chain-statuses"
+            ],
+            unsupported
+        );
+    }
+
+    #[tokio::test]
+    async fn test_command_resources() {
+        let (base_url, _) = serve_empty_object().await;
+        assert_eq!(
+            COMMAND_RESOURCES
+                .iter()
+                .map(|(command, _)| *command)
+                .collect::<Vec<_>>(),
+            COMMAND_ARGS
+                .iter()
+                .map(|(command, _)| *command)
+                .collect::<Vec<_>>()
+        );
+        for ((command, resource), (_, command_args)) in COMMAND_RESOURCES.iter().zip(COMMAND_ARGS) {
+            let mut client = CovalentClient::new("8217", "ckey_test")
+                .unwrap()
+                .with_drift_check(DriftMode::Collect);
+            client.base_url = base_url.clone();
+            let args = Args::try_parse_from(
+                ["covctl"]
+                    .into_iter()
+                    .chain(command.split(' '))
+                    .chain(command_args.iter().copied()),
+            )
+            .unwrap();
+            run(&client, args.action, args.format).await;
+
+            // the drift check names the struct each response was decoded into
+            let decoded: Vec<String> = client
+                .take_drift_reports()
+                .into_iter()
+                .map(|report| report.resource)
+                .collect();
+            // holders-snapshot is built from pages of token holders rather than decoded
+            let expected = match *resource {
+                "HoldersSnapshot" => "TokenHoldersData",
+                resource => resource,
+            };
+            assert!(!decoded.is_empty(), "{} made no request", command);
+            assert!(
+                decoded.iter().all(|decoded| decoded == expected),
+                "{} prints {:?} rather than {}",
+                command,
+                decoded,
+                resource
+            );
+        }
     }
 }
@@ -0,0 +1,603 @@
+//! Generating Rust source from the OpenAPI spec of the Covalent API
+//!
+//! Every schema in `components.schemas` becomes a resource struct and every path an `Endpoint`.
+//! `overrides.json` covers what the spec cannot express or gets wrong about the API:
+//! - `flatten` names the field a schema referenced from an `allOf` is flattened into
+//! - `no_extra` lists the structs which do not capture unknown fields in `extra`
+//! - `hand_written` lists the structs written in resources.rs rather than generated
+//! - `schemas` renames fields or replaces their types, with a comment on why
+//!
+//! Each struct gets a `with_` setter per field so resources can be built up from `Default`, and
+//! implements `drift::Modelled` to clear the `extra` maps of it and the structs it holds.
+//!
+//! With the `schemars` feature of covalent_class_a each struct derives `JsonSchema`, and
+//! `json_schema` looks the schema of a struct up by its name.
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Write;
+
+const HEADER: &str = "// @generated by `cargo run --manifest-path xtask/Cargo.toml -- codegen` from
+// spec/covalent.json and spec/overrides.json, edit those rather than this file\n";
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct Overrides {
+    pub flatten: BTreeMap<String, FlattenOverride>,
+    pub no_extra: Vec<String>,
+    pub hand_written: Vec<String>,
+    pub schemas: BTreeMap<String, SchemaOverride>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct FlattenOverride {
+    pub field: Option<String>,
+    pub optional: bool,
+    pub comment: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct SchemaOverride {
+    pub fields: BTreeMap<String, FieldOverride>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct FieldOverride {
+    pub rust_name: Option<String>,
+    pub rust_type: Option<String>,
+    pub comment: Option<String>,
+}
+
+/// The unformatted source of the generated files
+pub struct Generated {
+    pub resources: String,
+    pub endpoints: String,
+}
+
+/// A Rust type and the `amount` module to (de)serialize it with, if any
+#[derive(Debug, PartialEq)]
+struct RustType {
+    name: String,
+    with: Option<&'static str>,
+}
+
+impl RustType {
+    fn plain(name: &str) -> RustType {
+        RustType {
+            name: name.to_string(),
+            with: None,
+        }
+    }
+}
+
+/// A field of a resource struct, for its builder-style setter
+struct Field {
+    name: String,
+    ty: String,
+    optional: bool,
+}
+
+/// The name of a schema from a reference like `#/components/schemas/BalancesData`
+fn ref_name(reference: &str) -> Result<&str, Box<dyn Error>> {
+    reference
+        .strip_prefix("#/components/schemas/")
+        .ok_or_else(|| format!("Unsupported reference {}", reference).into())
+}
+
+/// The Rust type of a property schema
+fn rust_type(schema: &Value) -> Result<RustType, Box<dyn Error>> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return Ok(RustType::plain(ref_name(reference)?));
+    }
+    let format = schema.get("format").and_then(Value::as_str);
+    let rust_type = match schema.get("type").and_then(Value::as_str) {
+        Some("string") => match format {
+            Some("date-time") => RustType::plain("Timestamp"),
+            Some("uint256") => RustType {
+                name: "U256".to_string(),
+                with: Some("amount::u256"),
+            },
+            Some("int256") => RustType {
+                name: "I256".to_string(),
+                with: Some("amount::i256"),
+            },
+            _ => RustType::plain("String"),
+        },
+        Some("integer") => match format {
+            Some("int32") => RustType::plain("i32"),
+            _ if schema.get("minimum").and_then(Value::as_i64) >= Some(0) => RustType::plain("u64"),
+            _ => RustType::plain("i64"),
+        },
+        Some("number") => RustType::plain("f64"),
+        Some("boolean") => RustType::plain("bool"),
+        Some("array") => {
+            let items = rust_type(schema.get("items").unwrap_or(&Value::Null))?;
+            if items.with.is_some() {
+                return Err(format!("Unsupported array of {}", items.name).into());
+            }
+            RustType::plain(&format!("Vec<{}>", items.name))
+        }
+        // a property without a type can hold any JSON value
+        None => RustType::plain("Value"),
+        Some(other) => return Err(format!("Unsupported type {}", other).into()),
+    };
+    Ok(rust_type)
+}
+
+/// Write a description as doc comment lines
+fn write_doc(out: &mut String, indent: &str, description: Option<&str>) {
+    for line in description.into_iter().flat_map(str::lines) {
+        let _ = writeln!(out, "{}/// {}", indent, line);
+    }
+}
+
+fn write_comment(out: &mut String, comment: Option<&str>) {
+    for line in comment.into_iter().flat_map(str::lines) {
+        let _ = writeln!(out, "    // {}", line);
+    }
+}
+
+/// The field name of a flattened schema when no override names it e.g. `ApiError` is `error`
+fn flatten_field_name(schema_name: &str) -> String {
+    let name = schema_name.strip_prefix("Api").unwrap_or(schema_name);
+    let mut field = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            field.push('_');
+        }
+        field.push(c.to_ascii_lowercase());
+    }
+    field
+}
+
+fn write_properties(
+    out: &mut String,
+    fields: &mut Vec<Field>,
+    object: &Value,
+    overrides: Option<&SchemaOverride>,
+) -> Result<(), Box<dyn Error>> {
+    let required: Vec<&str> = object
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let empty = Map::new();
+    let properties = object
+        .get("properties")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    for (name, schema) in properties {
+        let field_override = overrides.and_then(|o| o.fields.get(name));
+        let rust_name = field_override
+            .and_then(|o| o.rust_name.as_deref())
+            .unwrap_or(name);
+        let optional = !required.contains(&name.as_str());
+
+        write_doc(
+            out,
+            "    ",
+            schema.get("description").and_then(Value::as_str),
+        );
+        write_comment(out, field_override.and_then(|o| o.comment.as_deref()));
+        if rust_name != name {
+            let _ = writeln!(out, "    #[serde(alias = \"{}\")]", name);
+        }
+        let ty = match field_override.and_then(|o| o.rust_type.as_deref()) {
+            Some(rust_type) => RustType::plain(rust_type),
+            None => rust_type(schema)?,
+        };
+        match (ty.with, optional) {
+            // amounts are strings in the JSON, which schemars is told as it cannot see through `with`
+            (Some(with), false) => {
+                let _ = writeln!(out, "    #[serde(with = \"{}\")]", with);
+                out.push_str("    #[cfg_attr(feature = \"schemars\", schemars(with = \"String\"))]\n");
+            }
+            (Some(with), true) => {
+                let _ = writeln!(out, "    #[serde(with = \"{}::option\", default)]", with);
+                out.push_str(
+                    "    #[cfg_attr(feature = \"schemars\", schemars(with = \"Option<String>\"))]\n",
+                );
+            }
+            (None, _) => (),
+        }
+        if optional {
+            let _ = writeln!(out, "    pub {}: Option<{}>,", rust_name, ty.name);
+        } else {
+            let _ = writeln!(out, "    pub {}: {},", rust_name, ty.name);
+        }
+        fields.push(Field {
+            name: rust_name.to_string(),
+            ty: ty.name,
+            optional,
+        });
+    }
+    Ok(())
+}
+
+/// The struct a field type holds, if any, e.g. `ContractMetadataItem` of
+/// `Vec<Vec<ContractMetadataItem>>`
+fn held_struct<'a>(ty: &'a str, structs: &[&str]) -> Option<&'a str> {
+    let mut held = ty;
+    while let Some(element) = held.strip_prefix("Vec<").and_then(|t| t.strip_suffix('>')) {
+        held = element;
+    }
+    structs.contains(&held).then_some(held)
+}
+
+/// The `Modelled` impl clearing the `extra` map of a resource and of the structs it holds
+fn write_modelled(out: &mut String, name: &str, fields: &[Field], extra: bool, structs: &[&str]) {
+    let mut clears: Vec<String> = fields
+        .iter()
+        .filter(|field| held_struct(&field.ty, structs).is_some())
+        .map(|field| format!("self.{}.clear_extra();", field.name))
+        .collect();
+    if extra {
+        clears.push("self.extra.clear();".to_string());
+    }
+    let _ = writeln!(out, "
+impl Modelled for {} {{", name);
+    if clears.is_empty() {
+        out.push_str("    fn clear_extra(&mut self) {}
+");
+    } else {
+        out.push_str("    fn clear_extra(&mut self) {
+");
+        for clear in clears {
+            let _ = writeln!(out, "        {}", clear);
+        }
+        out.push_str("    }
+");
+    }
+    out.push_str("}
+");
+}
+
+/// Builder-style setters to create a resource from `Default`, optional fields are set to `Some`
+fn write_setters(out: &mut String, name: &str, fields: &[Field]) {
+    if fields.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "\nimpl {} {{", name);
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let value = if field.optional {
+            format!("Some({})", field.name)
+        } else {
+            field.name.clone()
+        };
+        let _ = writeln!(
+            out,
+            "    pub fn with_{0}(mut self, {0}: {1}) -> Self {{
+        self.{0} = {2};
+        self
+    }}",
+            field.name, field.ty, value
+        );
+    }
+    out.push_str("}\n");
+}
+
+/// The resource struct of a schema, given the names of every struct it may hold
+fn resource(
+    name: &str,
+    schema: &Value,
+    overrides: &Overrides,
+    structs: &[&str],
+) -> Result<String, Box<dyn Error>> {
+    let mut out = String::new();
+    write_doc(
+        &mut out,
+        "",
+        schema.get("description").and_then(Value::as_str),
+    );
+    out.push_str("#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]\n");
+    out.push_str("#[cfg_attr(feature = \"schemars\", derive(schemars::JsonSchema))]\n");
+    let _ = writeln!(out, "pub struct {} {{", name);
+
+    let mut fields = vec![];
+    let parts = match schema.get("allOf").and_then(Value::as_array) {
+        Some(parts) => parts.iter().collect(),
+        None => vec![schema],
+    };
+    for part in parts {
+        match part.get("$ref").and_then(Value::as_str) {
+            Some(reference) => {
+                let flattened = ref_name(reference)?;
+                let flatten_override = overrides.flatten.get(flattened);
+                let field = flatten_override
+                    .and_then(|o| o.field.clone())
+                    .unwrap_or_else(|| flatten_field_name(flattened));
+                write_comment(
+                    &mut out,
+                    flatten_override.and_then(|o| o.comment.as_deref()),
+                );
+                out.push_str("    #[serde(flatten)]\n");
+                let optional = flatten_override.is_some_and(|o| o.optional);
+                if optional {
+                    let _ = writeln!(out, "    pub {}: Option<{}>,", field, flattened);
+                } else {
+                    let _ = writeln!(out, "    pub {}: {},", field, flattened);
+                }
+                fields.push(Field {
+                    name: field,
+                    ty: flattened.to_string(),
+                    optional,
+                });
+            }
+            None => write_properties(&mut out, &mut fields, part, overrides.schemas.get(name))?,
+        }
+    }
+
+    let extra = !overrides.no_extra.iter().any(|n| n == name);
+    if extra {
+        out.push_str("    #[serde(flatten)]\n");
+        out.push_str("    pub extra: Map<String, Value>,\n");
+    }
+    out.push_str("}\n");
+    write_setters(&mut out, name, &fields);
+    write_modelled(&mut out, name, &fields, extra, structs);
+    Ok(out)
+}
+
+fn resources(spec: &Value, overrides: &Overrides) -> Result<String, Box<dyn Error>> {
+    let schemas = spec
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .ok_or("The spec has no components.schemas")?;
+    let mut imported = vec!["Timestamp".to_string()];
+    imported.extend(overrides.hand_written.iter().cloned());
+    imported.sort();
+
+    let structs: Vec<&str> = schemas.keys().map(String::as_str).collect();
+
+    let mut out = String::from(HEADER);
+    let _ = writeln!(out, "use super::{{{}}};", imported.join(", "));
+    out.push_str("use crate::amount::{self, I256, U256};\n");
+    out.push_str("use crate::drift::Modelled;\n");
+    out.push_str("use serde::{Deserialize, Serialize};\n");
+    out.push_str("use serde_json::{Map, Value};\n");
+    for (name, schema) in schemas {
+        if overrides.hand_written.contains(name) {
+            continue;
+        }
+        out.push('\n');
+        out.push_str(&resource(name, schema, overrides, &structs)?);
+    }
+
+    out.push_str(
+        "
+/// The JSON Schema of a resource by its struct name e.g. `BalancesData`
+#[cfg(feature = \"schemars\")]
+pub fn json_schema(resource: &str) -> Option<schemars::schema::RootSchema> {
+    let schema = match resource {\n",
+    );
+    for name in schemas.keys() {
+        let _ = writeln!(out, "        {:?} => schemars::schema_for!({}),", name, name);
+    }
+    out.push_str("        _ => return None,\n    };\n    Some(schema)\n}\n");
+    Ok(out)
+}
+
+/// A parameter, following a reference into `components.parameters`
+fn parameter<'a>(spec: &'a Value, parameter: &'a Value) -> Result<&'a Value, Box<dyn Error>> {
+    match parameter.get("$ref").and_then(Value::as_str) {
+        Some(reference) => {
+            let pointer = reference.trim_start_matches('#');
+            spec.pointer(pointer)
+                .ok_or_else(|| format!("Unknown parameter {}", reference).into())
+        }
+        None => Ok(parameter),
+    }
+}
+
+fn string_list(items: &[&str]) -> String {
+    let quoted: Vec<String> = items.iter().map(|item| format!("{:?}", item)).collect();
+    format!("&[{}]", quoted.join(", "))
+}
+
+fn endpoints(spec: &Value) -> Result<String, Box<dyn Error>> {
+    let paths = spec
+        .get("paths")
+        .and_then(Value::as_object)
+        .ok_or("The spec has no paths")?;
+    let mut out = String::from(HEADER);
+    out.push_str(
+        "//! The endpoints of the API as described by the vendored spec
+
+/// An endpoint of the API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endpoint {
+    pub operation_id: &'static str,
+    pub summary: &'static str,
+    // relative to the base URL with `{name}` placeholders for the path parameters
+    pub path: &'static str,
+    pub path_params: &'static [&'static str],
+    pub query_params: &'static [&'static str],
+    // the resource struct the response is decoded into
+    pub resource: &'static str,
+}
+
+/// Find an endpoint by its operation ID e.g. `getTokenBalances`
+pub fn endpoint(operation_id: &str) -> Option<&'static Endpoint> {
+    ENDPOINTS
+        .iter()
+        .find(|endpoint| endpoint.operation_id == operation_id)
+}
+
+pub const ENDPOINTS: &[Endpoint] = &[\n",
+    );
+    for (path, item) in paths {
+        let operation = item
+            .get("get")
+            .ok_or_else(|| format!("{} has no GET operation", path))?;
+        let field = |name: &str| {
+            operation
+                .get(name)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+        };
+        let mut path_params = vec![];
+        let mut query_params = vec![];
+        for param in operation
+            .get("parameters")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+        {
+            let param = parameter(spec, param)?;
+            let name = param
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            match param.get("in").and_then(Value::as_str) {
+                Some("path") => path_params.push(name),
+                _ => query_params.push(name),
+            }
+        }
+        let resource = operation
+            .pointer("/responses/200/content/application~1json/schema/$ref")
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("{} has no JSON response schema", path))?;
+        let _ = write!(
+            out,
+            "    Endpoint {{
+        operation_id: {:?},
+        summary: {:?},
+        path: {:?},
+        path_params: {},
+        query_params: {},
+        resource: {:?},
+    }},\n",
+            field("operationId"),
+            field("summary"),
+            path,
+            string_list(&path_params),
+            string_list(&query_params),
+            ref_name(resource)?,
+        );
+    }
+    out.push_str("];\n");
+    Ok(out)
+}
+
+/// Generate the resource structs and endpoint definitions of a spec
+pub fn generate(spec: &Value, overrides: &Value) -> Result<Generated, Box<dyn Error>> {
+    let overrides: Overrides = serde_json::from_value(overrides.clone())?;
+    Ok(Generated {
+        resources: resources(spec, &overrides)?,
+        endpoints: endpoints(spec)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_rust_type() {
+        assert_eq!(
+            RustType {
+                name: "U256".to_string(),
+                with: Some("amount::u256")
+            },
+            rust_type(&json!({"type": "string", "format": "uint256"})).unwrap()
+        );
+        assert_eq!(
+            RustType::plain("Vec<u64>"),
+            rust_type(&json!({"type": "array", "items": {"type": "integer", "minimum": 0}}))
+                .unwrap()
+        );
+        assert_eq!(RustType::plain("Value"), rust_type(&json!({})).unwrap());
+        assert!(rust_type(
+            &json!({"type": "array", "items": {"type": "string", "format": "uint256"}})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_held_struct() {
+        let structs = ["ContractMetadataItem"];
+        assert_eq!(
+            Some("ContractMetadataItem"),
+            held_struct("Vec<Vec<ContractMetadataItem>>", &structs)
+        );
+        assert_eq!(None, held_struct("Vec<u64>", &structs));
+    }
+
+    #[test]
+    fn test_resource() {
+        let schema = json!({
+            "allOf": [
+                {
+                    "type": "object",
+                    "required": ["type", "balance"],
+                    "properties": {
+                        "type": {"type": "string"},
+                        "balance": {"type": "string", "format": "uint256", "description": "The raw balance"},
+                        "quote": {"type": "number"}
+                    }
+                },
+                {"$ref": "#/components/schemas/ApiError"}
+            ]
+        });
+        let overrides: Overrides = serde_json::from_value(json!({
+            "schemas": {"BalanceItem": {"fields": {"type": {"rust_name": "balance_type"}}}}
+        }))
+        .unwrap();
+        assert_eq!(
+            "#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = \"schemars\", derive(schemars::JsonSchema))]
+pub struct BalanceItem {
+    #[serde(alias = \"type\")]
+    pub balance_type: String,
+    /// The raw balance
+    #[serde(with = \"amount::u256\")]
+    #[cfg_attr(feature = \"schemars\", schemars(with = \"String\"))]
+    pub balance: U256,
+    pub quote: Option<f64>,
+    #[serde(flatten)]
+    pub error: ApiError,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BalanceItem {
+    pub fn with_balance_type(mut self, balance_type: String) -> Self {
+        self.balance_type = balance_type;
+        self
+    }
+
+    pub fn with_balance(mut self, balance: U256) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    pub fn with_quote(mut self, quote: f64) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    pub fn with_error(mut self, error: ApiError) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl Modelled for BalanceItem {
+    fn clear_extra(&mut self) {
+        self.error.clear_extra();
+        self.extra.clear();
+    }
+}
+",
+            resource("BalanceItem", &schema, &overrides, &["ApiError", "BalanceItem"]).unwrap()
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Development tasks for covalent_class_a
+//!
+//! `cargo run --manifest-path xtask/Cargo.toml -- codegen` regenerates the resource structs and
+//! endpoint definitions from `covalent_class_a/spec`, and `codegen --check` fails if the
+//! checked-in files are out of date with the spec.
+mod codegen;
+
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+/// The covalent_class_a crate directory, next to this crate
+fn crate_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("covalent_class_a")
+}
+
+/// Format source code with rustfmt
+fn rustfmt(source: &str) -> Result<String, Box<dyn Error>> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(source.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err("rustfmt failed to format the generated code".into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Generate the files from the spec, writing them or checking they are up to date
+fn codegen(check: bool) -> Result<(), Box<dyn Error>> {
+    let crate_dir = crate_dir();
+    let spec = fs::read_to_string(crate_dir.join("spec/covalent.json"))?;
+    let overrides = fs::read_to_string(crate_dir.join("spec/overrides.json"))?;
+    let generated = codegen::generate(
+        &serde_json::from_str(&spec)?,
+        &serde_json::from_str(&overrides)?,
+    )?;
+
+    let mut stale = vec![];
+    for (path, source) in [
+        ("src/resources/generated.rs", generated.resources),
+        ("src/endpoints.rs", generated.endpoints),
+    ] {
+        let source = rustfmt(&source)?;
+        let path = crate_dir.join(path);
+        if check {
+            if fs::read_to_string(&path).ok().as_deref() != Some(source.as_str()) {
+                stale.push(path.display().to_string());
+            }
+        } else {
+            fs::write(&path, source)?;
+            println!("Generated {}", path.display());
+        }
+    }
+    if !stale.is_empty() {
+        return Err(format!(
+            "Out of date with the spec, run codegen to regenerate: {}",
+            stale.join(", ")
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("codegen") => codegen(args.iter().any(|arg| arg == "--check")),
+        _ => Err("Usage: xtask codegen [--check]".into()),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}